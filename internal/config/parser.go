@@ -1,21 +1,100 @@
 package config
 
 import (
-	"embed"
+	"bytes"
+	"errors"
 	"fmt"
+	"io"
 	"io/fs"
+	"os"
 	"path/filepath"
 	"sort"
+	"strings"
+	"time"
 
 	"gopkg.in/yaml.v3"
 )
 
-// ParseTemplates walks the embedded templates directory tree, reading
-// all .yaml files from category subdirectories (e.g. templates/dns/*.yaml).
-func ParseTemplates(fsys embed.FS) ([]Template, error) {
+// Override records a template replaced by a later search directory.
+type Override struct {
+	Name string
+	Path string // directory the winning definition came from
+}
+
+// LoadTemplates walks the embedded templates directory tree, reading
+// all .yaml files from category subdirectories (e.g. templates/dns/*.yaml),
+// then layers each directory in dirs on top (same <category>/*.yaml
+// layout). Later sources override earlier ones by template name.
+// Directories that don't exist are skipped.
+func LoadTemplates(embedded fs.FS, dirs []string) ([]Template, []Override, error) {
+	base, err := parseTree(embedded, "templates")
+	if err != nil {
+		return nil, nil, err
+	}
+	for i := range base {
+		base[i].Source = "embedded:" + base[i].Source
+	}
+
+	byName := make(map[string]int, len(base))
+	for i, t := range base {
+		byName[t.Name] = i
+	}
+
+	var overrides []Override
+	for _, dir := range dirs {
+		if info, err := os.Stat(dir); err != nil || !info.IsDir() {
+			continue
+		}
+		extra, err := parseTree(os.DirFS(dir), ".")
+		if err != nil {
+			return nil, nil, fmt.Errorf("%s: %w", dir, err)
+		}
+		for _, t := range extra {
+			t.Source = filepath.Join(dir, t.Source)
+			if i, ok := byName[t.Name]; ok {
+				base[i] = t
+				overrides = append(overrides, Override{Name: t.Name, Path: dir})
+				continue
+			}
+			byName[t.Name] = len(base)
+			base = append(base, t)
+		}
+	}
+
+	sortTemplates(base)
+	return base, overrides, nil
+}
+
+// TemplateDirs returns the user template search path in override order:
+// $XDG_CONFIG_HOME/ipcrawler/templates (or ~/.config/ipcrawler/templates),
+// followed by each entry of the colon-separated IPCRAWLER_TEMPLATES.
+func TemplateDirs() []string {
+	var dirs []string
+
+	cfgHome := os.Getenv("XDG_CONFIG_HOME")
+	if cfgHome == "" {
+		if home, err := os.UserHomeDir(); err == nil {
+			cfgHome = filepath.Join(home, ".config")
+		}
+	}
+	if cfgHome != "" {
+		dirs = append(dirs, filepath.Join(cfgHome, "ipcrawler", "templates"))
+	}
+
+	for _, d := range filepath.SplitList(os.Getenv("IPCRAWLER_TEMPLATES")) {
+		if d = strings.TrimSpace(d); d != "" {
+			dirs = append(dirs, d)
+		}
+	}
+	return dirs
+}
+
+// parseTree reads every .yaml file under root in fsys. Each template's
+// Source is set to its path within fsys.
+func parseTree(fsys fs.FS, root string) ([]Template, error) {
 	var templates []Template
 
-	err := fs.WalkDir(fsys, "templates", func(path string, d fs.DirEntry, err error) error {
+	err := fs.WalkDir(fsys, root, func(path string, d fs.DirEntry, err error) error {
 		if err != nil {
 			return err
 		}
@@ -23,13 +102,17 @@ func ParseTemplates(fsys embed.FS) ([]Template, error) {
 			return nil
 		}
 
-		data, err := fsys.ReadFile(path)
+		data, err := fs.ReadFile(fsys, path)
 		if err != nil {
 			return fmt.Errorf("reading %s: %w", path, err)
 		}
 
+		// Unknown keys are errors so a typo like "timout" doesn't
+		// silently fall back to the default.
 		var t Template
-		if err := yaml.Unmarshal(data, &t); err != nil {
+		dec := yaml.NewDecoder(bytes.NewReader(data))
+		dec.KnownFields(true)
+		if err := dec.Decode(&t); err != nil && !errors.Is(err, io.EOF) {
 			return fmt.Errorf("parsing %s: %w", path, err)
 		}
 
@@ -42,24 +125,28 @@ func ParseTemplates(fsys embed.FS) ([]Template, error) {
 			t.Priority = 50
 		}
 
+		t.Source = path
 		templates = append(templates, t)
 		return nil
 	})
 	if err != nil {
 		return nil, err
 	}
+	return templates, nil
+}
 
+// sortTemplates orders templates by category, then name.
+func sortTemplates(templates []Template) {
 	sort.Slice(templates, func(i, j int) bool {
 		if templates[i].Category == templates[j].Category {
 			return templates[i].Name < templates[j].Name
 		}
 		return templates[i].Category < templates[j].Category
 	})
-
-	return templates, nil
 }
 
-// validate checks that required fields are present.
+// validate checks that required fields are present and that the
+// optional ones hold values the engine understands.
 func validate(t Template, filename string) error {
 	if t.Name == "" {
 		return fmt.Errorf("%s: missing required field 'name'", filename)
@@ -70,5 +157,15 @@ func validate(t Template, filename string) error {
 	if t.Category == "" {
 		return fmt.Errorf("%s: missing required field 'category'", filename)
 	}
+	if t.Timeout != "" {
+		if d, err := time.ParseDuration(t.Timeout); err != nil || d <= 0 {
+			return fmt.Errorf("%s: invalid timeout %q (use a duration like 90s or 5m)", filename, t.Timeout)
+		}
+	}
+	switch t.TargetType {
+	case "", "ip", "domain", "both":
+	default:
+		return fmt.Errorf("%s: invalid target_type %q (use ip, domain, or both)", filename, t.TargetType)
+	}
 	return nil
 }
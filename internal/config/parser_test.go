@@ -0,0 +1,97 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+	"testing/fstest"
+)
+
+func TestLoadTemplatesOverrides(t *testing.T) {
+	embedded := fstest.MapFS{
+		"templates/network/ping.yaml": {Data: []byte("name: Ping\ncommand: ping -c 4 {target}\ncategory: network\n")},
+		"templates/dns/whois.yaml":    {Data: []byte("name: Whois\ncommand: whois {target}\ncategory: dns\npriority: 20\n")},
+	}
+
+	orgDir := t.TempDir()
+	userDir := t.TempDir()
+	writeTemplate(t, orgDir, "network/ping.yaml", "name: Ping\ncommand: ping -c 1 {target}\ncategory: network\n")
+	writeTemplate(t, orgDir, "web/nikto.yaml", "name: Nikto\ncommand: nikto -h {target}\ncategory: web\n")
+	writeTemplate(t, userDir, "network/ping.yaml", "name: Ping\ncommand: ping -c 2 {target}\ncategory: network\n")
+
+	missing := filepath.Join(t.TempDir(), "does-not-exist")
+	templates, overrides, err := LoadTemplates(embedded, []string{orgDir, missing, userDir})
+	if err != nil {
+		t.Fatalf("LoadTemplates: %v", err)
+	}
+
+	got := map[string]Template{}
+	for _, tmpl := range templates {
+		got[tmpl.Name] = tmpl
+	}
+	if len(got) != 3 {
+		t.Fatalf("expected 3 templates, got %d", len(got))
+	}
+	if got["Ping"].Command != "ping -c 2 {target}" {
+		t.Errorf("Ping command = %q, want last directory to win", got["Ping"].Command)
+	}
+	if want := filepath.Join(userDir, "network", "ping.yaml"); got["Ping"].Source != want {
+		t.Errorf("Ping source = %q, want %q", got["Ping"].Source, want)
+	}
+	if got["Whois"].Source != "embedded:templates/dns/whois.yaml" {
+		t.Errorf("Whois source = %q, want the embedded path", got["Whois"].Source)
+	}
+	if got["Nikto"].Priority != 50 {
+		t.Errorf("Nikto priority = %d, want default 50", got["Nikto"].Priority)
+	}
+	if len(overrides) != 2 || overrides[1].Path != userDir {
+		t.Errorf("overrides = %+v, want two Ping overrides ending in %s", overrides, userDir)
+	}
+
+	// Sorted by category, then name.
+	if templates[0].Name != "Whois" || templates[2].Name != "Nikto" {
+		t.Errorf("unexpected order: %s, %s, %s", templates[0].Name, templates[1].Name, templates[2].Name)
+	}
+}
+
+func TestLoadTemplatesInvalidUserTemplate(t *testing.T) {
+	dir := t.TempDir()
+	writeTemplate(t, dir, "web/broken.yaml", "name: Broken\ncategory: web\n")
+
+	embedded := fstest.MapFS{
+		"templates/network/ping.yaml": {Data: []byte("name: Ping\ncommand: ping -c 4 {target}\ncategory: network\n")},
+	}
+	if _, _, err := LoadTemplates(embedded, []string{dir}); err == nil {
+		t.Fatal("expected error for template missing 'command'")
+	}
+}
+
+func TestLoadTemplatesRejectsBadFields(t *testing.T) {
+	tests := map[string]string{
+		"unknown key":         "name: Ping\ncommand: ping {target}\ncategory: network\ntimout: 30s\n",
+		"invalid timeout":     "name: Ping\ncommand: ping {target}\ncategory: network\ntimeout: soon\n",
+		"negative timeout":    "name: Ping\ncommand: ping {target}\ncategory: network\ntimeout: -5s\n",
+		"invalid target_type": "name: Ping\ncommand: ping {target}\ncategory: network\ntarget_type: ipv4\n",
+	}
+	for name, body := range tests {
+		t.Run(name, func(t *testing.T) {
+			embedded := fstest.MapFS{
+				"templates/network/ping.yaml": {Data: []byte(body)},
+			}
+			if _, _, err := LoadTemplates(embedded, nil); err == nil {
+				t.Error("expected an error")
+			}
+		})
+	}
+}
+
+func writeTemplate(t *testing.T, dir, rel, body string) {
+	t.Helper()
+	path := filepath.Join(dir, rel)
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(path, []byte(body), 0644); err != nil {
+		t.Fatal(err)
+	}
+}
@@ -1,6 +1,7 @@
 package config
 
 import (
+	"net"
 	"regexp"
 	"strings"
 	"time"
@@ -9,17 +10,22 @@ import (
 // Template defines a single tool's YAML schema.
 // Each YAML file in templates/ maps directly to this struct.
 type Template struct {
-	Name        string   `yaml:"name"`
-	Description string   `yaml:"description"`
-	Command     string   `yaml:"command"`
-	Category    string   `yaml:"category"`
-	Timeout     string   `yaml:"timeout"`
+	Name         string   `yaml:"name"`
+	Description  string   `yaml:"description"`
+	Command      string   `yaml:"command"`
+	Category     string   `yaml:"category"`
+	Timeout      string   `yaml:"timeout"`
 	Tags         []string `yaml:"tags"`
 	TargetType   string   `yaml:"target_type"` // "ip", "domain", or "both"
 	Sudo         bool     `yaml:"sudo"`
 	OutputFormat string   `yaml:"output_format"`
-	Priority     int      `yaml:"priority"`     // execution wave: lower runs first (default 50)
-	DependsOn    []string `yaml:"depends_on"`   // tool names that must finish before this runs
+	Priority     int      `yaml:"priority"`      // execution wave: lower runs first (default 50)
+	DependsOn    []string `yaml:"depends_on"`    // tool names that must finish before this runs
+	MaxOutputMB  int      `yaml:"max_output_mb"` // per-stream capture cap (default 50)
+
+	// Source is the file the template was loaded from: "embedded:<path>"
+	// for templates built into the binary, a file path for user templates.
+	Source string `yaml:"-"`
 }
 
 // TimeoutDuration parses the timeout string into a time.Duration.
@@ -32,13 +38,51 @@ func (t Template) TimeoutDuration() time.Duration {
 	return d
 }
 
-// ResolveCommand replaces the {target} placeholder with the actual target.
+// MaxOutputBytes returns the per-stream capture cap in bytes.
+// Falls back to 50MB if unset.
+func (t Template) MaxOutputBytes() int64 {
+	if t.MaxOutputMB <= 0 {
+		return 50 << 20
+	}
+	return int64(t.MaxOutputMB) << 20
+}
+
+// ResolveCommand replaces the {target}, {host}, and {cidr} placeholders.
 func (t Template) ResolveCommand(target string) string {
-	return strings.ReplaceAll(t.Command, "{target}", target)
+	cmd := strings.ReplaceAll(t.Command, "{target}", target)
+	cmd = strings.ReplaceAll(cmd, "{host}", URLHost(target))
+	return strings.ReplaceAll(cmd, "{cidr}", CIDR(target))
 }
 
+// URLHost returns target as it must appear in a URL: IPv6 literals are
+// bracketed (http://[2001:db8::1]), anything else is returned as-is.
+func URLHost(target string) string {
+	if strings.Contains(target, ":") && net.ParseIP(target) != nil {
+		return "[" + target + "]"
+	}
+	return target
+}
+
+// CIDR returns target as a network range: a single address gets the
+// host-length prefix for its family (/32 or /128), anything else
+// (a range or a domain) is returned as-is.
+func CIDR(target string) string {
+	ip := net.ParseIP(target)
+	switch {
+	case ip == nil:
+		return target
+	case ip.To4() != nil:
+		return target + "/32"
+	default:
+		return target + "/128"
+	}
+}
+
+// nonAlnum matches runs of characters that are unsafe in filenames.
+// Compiled once — SanitizeName is called per tool on every file path.
+var nonAlnum = regexp.MustCompile(`[^a-zA-Z0-9]+`)
+
 // SanitizeName converts a template name into a safe filename.
 func SanitizeName(name string) string {
-	re := regexp.MustCompile(`[^a-zA-Z0-9]+`)
-	return strings.ToLower(strings.Trim(re.ReplaceAllString(name, "_"), "_"))
+	return strings.ToLower(strings.Trim(nonAlnum.ReplaceAllString(name, "_"), "_"))
 }
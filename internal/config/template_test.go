@@ -0,0 +1,18 @@
+package config
+
+import "testing"
+
+func TestResolveCommandCIDR(t *testing.T) {
+	tmpl := Template{Command: "dnsrecon -r {cidr} -t rvl"}
+
+	tests := map[string]string{
+		"10.0.0.1":    "dnsrecon -r 10.0.0.1/32 -t rvl",
+		"2001:db8::1": "dnsrecon -r 2001:db8::1/128 -t rvl",
+		"10.0.0.0/24": "dnsrecon -r 10.0.0.0/24 -t rvl",
+	}
+	for target, want := range tests {
+		if got := tmpl.ResolveCommand(target); got != want {
+			t.Errorf("ResolveCommand(%q) = %q, want %q", target, got, want)
+		}
+	}
+}
@@ -0,0 +1,43 @@
+package fsutil
+
+import (
+	"errors"
+	"io"
+	"os"
+	"path/filepath"
+	"testing"
+)
+
+func TestWriteAtomicInterrupted(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "report.md")
+	if err := os.WriteFile(path, []byte("previous report\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	// Simulate a write that dies halfway through.
+	interrupted := errors.New("interrupted")
+	err := WriteAtomic(path, func(w io.Writer) error {
+		_, _ = io.WriteString(w, "# IPCrawler Report\n\npartial")
+		return interrupted
+	})
+	if !errors.Is(err, interrupted) {
+		t.Fatalf("WriteAtomic error = %v, want %v", err, interrupted)
+	}
+
+	got, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(got) != "previous report\n" {
+		t.Errorf("report.md = %q, want previous content untouched", got)
+	}
+
+	entries, err := os.ReadDir(dir)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(entries) != 1 {
+		t.Errorf("expected only report.md in dir, got %d entries", len(entries))
+	}
+}
@@ -0,0 +1,20 @@
+package fsutil
+
+import (
+	"os"
+	"path/filepath"
+)
+
+// ScanSubdirs are the directories every scan directory holds: tool
+// output in raw/, stderr in errors/, and engine logs in logs/.
+var ScanSubdirs = []string{"raw", "errors", "logs"}
+
+// MakeScanDirs creates dir and its ScanSubdirs.
+func MakeScanDirs(dir string) error {
+	for _, d := range ScanSubdirs {
+		if err := os.MkdirAll(filepath.Join(dir, d), 0755); err != nil {
+			return err
+		}
+	}
+	return nil
+}
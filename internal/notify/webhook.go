@@ -0,0 +1,104 @@
+// Package notify posts a short scan summary to a chat webhook when a run
+// finishes, so long unattended scans can ping Slack or Discord.
+package notify
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"os"
+	"sort"
+	"strings"
+	"time"
+)
+
+// EnvWebhookURL names the environment variable holding the webhook URL.
+// An env var rather than a config file keeps the URL, which embeds the
+// webhook's secret token, out of the scans directory.
+const EnvWebhookURL = "IPCRAWLER_WEBHOOK_URL"
+
+// Timeout bounds the POST so a dead webhook never holds up the scan.
+const Timeout = 10 * time.Second
+
+// Summary is what gets reported about a finished scan.
+type Summary struct {
+	Target      string
+	OutputDir   string
+	Duration    time.Duration
+	Interrupted bool
+	Statuses    map[string]int // report status ("Success", "Failed", ...) → tool count
+}
+
+// payload carries text for Slack (text) and Discord (content) incoming
+// webhooks, plus structured fields for anything else consuming it.
+type payload struct {
+	Text        string         `json:"text"`
+	Content     string         `json:"content"`
+	Target      string         `json:"target"`
+	OutputDir   string         `json:"output_dir"`
+	DurationSec float64        `json:"duration_seconds"`
+	Interrupted bool           `json:"interrupted"`
+	Statuses    map[string]int `json:"statuses"`
+}
+
+// WebhookURL returns the configured webhook URL, or "" if none is set.
+func WebhookURL() string {
+	return strings.TrimSpace(os.Getenv(EnvWebhookURL))
+}
+
+// Send POSTs s to webhookURL as JSON. Errors never include the URL, since
+// it contains the webhook's secret token.
+func Send(webhookURL string, s Summary) error {
+	body, err := json.Marshal(payload{
+		Text:        s.text(),
+		Content:     s.text(),
+		Target:      s.Target,
+		OutputDir:   s.OutputDir,
+		DurationSec: s.Duration.Round(time.Second).Seconds(),
+		Interrupted: s.Interrupted,
+		Statuses:    s.Statuses,
+	})
+	if err != nil {
+		return err
+	}
+
+	client := &http.Client{Timeout: Timeout}
+	resp, err := client.Post(webhookURL, "application/json", bytes.NewReader(body))
+	if err != nil {
+		var ue *url.Error
+		if errors.As(err, &ue) {
+			err = ue.Err
+		}
+		return fmt.Errorf("webhook POST failed: %w", err)
+	}
+	defer func() { _ = resp.Body.Close() }()
+	if resp.StatusCode < 200 || resp.StatusCode > 299 {
+		return fmt.Errorf("webhook returned %s", resp.Status)
+	}
+	return nil
+}
+
+// text renders the one-line human summary, e.g.
+// "ipcrawler finished 10.0.0.1 in 4m12s — 5 Success, 1 Failed (scans/...)".
+func (s Summary) text() string {
+	verb := "finished"
+	if s.Interrupted {
+		verb = "was interrupted scanning"
+	}
+
+	names := make([]string, 0, len(s.Statuses))
+	for name := range s.Statuses {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	counts := make([]string, 0, len(names))
+	for _, name := range names {
+		counts = append(counts, fmt.Sprintf("%d %s", s.Statuses[name], name))
+	}
+
+	return fmt.Sprintf("ipcrawler %s %s in %s — %s (%s)",
+		verb, s.Target, s.Duration.Round(time.Second), strings.Join(counts, ", "), s.OutputDir)
+}
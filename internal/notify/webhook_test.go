@@ -0,0 +1,59 @@
+package notify
+
+import (
+	"encoding/json"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestSendPayload(t *testing.T) {
+	var got map[string]any
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		if ct := r.Header.Get("Content-Type"); ct != "application/json" {
+			t.Errorf("Content-Type = %q, want application/json", ct)
+		}
+		if err := json.NewDecoder(r.Body).Decode(&got); err != nil {
+			t.Errorf("decode payload: %v", err)
+		}
+	}))
+	defer srv.Close()
+
+	err := Send(srv.URL, Summary{
+		Target:    "10.0.0.1",
+		OutputDir: "scans/10.0.0.1_1200_01-01",
+		Duration:  90 * time.Second,
+		Statuses:  map[string]int{"Success": 5, "Failed": 1},
+	})
+	if err != nil {
+		t.Fatalf("Send: %v", err)
+	}
+
+	want := "ipcrawler finished 10.0.0.1 in 1m30s — 1 Failed, 5 Success (scans/10.0.0.1_1200_01-01)"
+	if got["text"] != want || got["content"] != want {
+		t.Errorf("text/content = %q / %q, want %q", got["text"], got["content"], want)
+	}
+	if got["target"] != "10.0.0.1" || got["duration_seconds"] != 90.0 {
+		t.Errorf("payload = %v, want target and duration_seconds fields", got)
+	}
+}
+
+func TestSendErrorHidesURL(t *testing.T) {
+	srv := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusForbidden)
+	}))
+	defer srv.Close()
+
+	err := Send(srv.URL+"/hooks/SECRET", Summary{Target: "example.com"})
+	if err == nil {
+		t.Fatal("expected an error for a 403 response")
+	}
+
+	srv.Close()
+	err = Send(srv.URL+"/hooks/SECRET", Summary{Target: "example.com"})
+	if err == nil || strings.Contains(err.Error(), "SECRET") {
+		t.Errorf("connection error = %v, want an error without the webhook URL", err)
+	}
+}
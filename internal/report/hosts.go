@@ -0,0 +1,433 @@
+package report
+
+import (
+	"bufio"
+	"bytes"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"net"
+	"os"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+)
+
+// Markers the Hosts Updater template wraps its /etc/hosts entries in.
+const (
+	hostsBlockStart = "# ipcrawler START"
+	hostsBlockEnd   = "# ipcrawler END"
+)
+
+// hostEntry is a single IP → hostname mapping.
+type hostEntry struct {
+	IP   string
+	Host string
+}
+
+// WriteHostsAdditions reads {outputDir}/raw/dns_resolved.txt (fed by the
+// recon templates) and writes a deduplicated, column-aligned
+// {outputDir}/hosts_additions.txt ready to paste into /etc/hosts.
+// Returns the number of entries written; 0 with a nil error means there
+// was nothing to write.
+func WriteHostsAdditions(outputDir string) (int, error) {
+	f, err := os.Open(filepath.Join(outputDir, "raw", "dns_resolved.txt"))
+	if os.IsNotExist(err) {
+		return 0, nil
+	}
+	if err != nil {
+		return 0, fmt.Errorf("open dns_resolved.txt: %w", err)
+	}
+	defer func() { _ = f.Close() }()
+
+	entries, err := parseHostEntries(f)
+	if err != nil {
+		return 0, fmt.Errorf("read dns_resolved.txt: %w", err)
+	}
+	if len(entries) == 0 {
+		return 0, nil
+	}
+
+	path := filepath.Join(outputDir, "hosts_additions.txt")
+	err = fsutil.WriteAtomic(path, func(w io.Writer) error {
+		_, err := io.WriteString(w, formatHostEntries(entries))
+		return err
+	})
+	if err != nil {
+		return 0, err
+	}
+	return len(entries), nil
+}
+
+// FilterResolvedHosts drops the lines of {outputDir}/raw/dns_resolved.txt
+// whose IP falls inside one of exclude (IP addresses or CIDR ranges), so
+// out-of-scope hosts found by the recon templates never reach /etc/hosts
+// or hosts_additions.txt. It returns the dropped lines; a missing file is
+// not an error.
+func FilterResolvedHosts(outputDir string, exclude []string) ([]string, error) {
+	if len(exclude) == 0 {
+		return nil, nil
+	}
+	path := filepath.Join(outputDir, "raw", "dns_resolved.txt")
+	data, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return nil, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("read dns_resolved.txt: %w", err)
+	}
+
+	var kept bytes.Buffer
+	var dropped []string
+	for _, line := range strings.SplitAfter(string(data), "\n") {
+		fields := strings.Fields(line)
+		if len(fields) > 0 && hostExcluded(fields[0], exclude) {
+			dropped = append(dropped, strings.TrimSpace(line))
+			continue
+		}
+		kept.WriteString(line)
+	}
+	if len(dropped) == 0 {
+		return nil, nil
+	}
+
+	err = fsutil.WriteAtomic(path, func(w io.Writer) error {
+		_, err := w.Write(kept.Bytes())
+		return err
+	})
+	if err != nil {
+		return nil, err
+	}
+	return dropped, nil
+}
+
+// DedupeResolvedHosts removes repeated lines from
+// {outputDir}/raw/dns_resolved.txt, keeping the first of each. A tool
+// re-run on resume appends the entries it had already written. It returns
+// the number of lines removed; a missing file is not an error.
+func DedupeResolvedHosts(outputDir string) (int, error) {
+	path := filepath.Join(outputDir, "raw", "dns_resolved.txt")
+	data, err := os.ReadFile(path)
+	if os.IsNotExist(err) {
+		return 0, nil
+	}
+	if err != nil {
+		return 0, fmt.Errorf("read dns_resolved.txt: %w", err)
+	}
+
+	seen := map[string]bool{}
+	var kept bytes.Buffer
+	removed := 0
+	for _, line := range strings.SplitAfter(string(data), "\n") {
+		key := strings.Join(strings.Fields(line), " ")
+		if key != "" && seen[key] {
+			removed++
+			continue
+		}
+		seen[key] = true
+		kept.WriteString(line)
+	}
+	if removed == 0 {
+		return 0, nil
+	}
+
+	err = fsutil.WriteAtomic(path, func(w io.Writer) error {
+		_, err := w.Write(kept.Bytes())
+		return err
+	})
+	if err != nil {
+		return 0, err
+	}
+	return removed, nil
+}
+
+// hostExcluded reports whether addr is one of exclude's IP addresses or
+// inside one of its CIDR ranges.
+func hostExcluded(addr string, exclude []string) bool {
+	ip := net.ParseIP(addr)
+	if ip == nil {
+		return false
+	}
+	for _, e := range exclude {
+		if _, network, err := net.ParseCIDR(e); err == nil {
+			if network.Contains(ip) {
+				return true
+			}
+		} else if ex := net.ParseIP(e); ex != nil && ex.Equal(ip) {
+			return true
+		}
+	}
+	return false
+}
+
+// parseHostEntries reads "IP hostname" lines, skipping blanks, comments,
+// and lines whose first field is not an IP. Duplicate pairs are dropped;
+// first-seen order is preserved.
+func parseHostEntries(r io.Reader) ([]hostEntry, error) {
+	seen := map[hostEntry]bool{}
+	var entries []hostEntry
+
+	scanner := bufio.NewScanner(r)
+	for scanner.Scan() {
+		fields := strings.Fields(scanner.Text())
+		if len(fields) < 2 || strings.HasPrefix(fields[0], "#") {
+			continue
+		}
+		if net.ParseIP(fields[0]) == nil {
+			continue
+		}
+		e := hostEntry{IP: fields[0], Host: strings.ToLower(strings.TrimSuffix(fields[1], "."))}
+		if e.Host == "" || seen[e] {
+			continue
+		}
+		seen[e] = true
+		entries = append(entries, e)
+	}
+	return entries, scanner.Err()
+}
+
+// formatHostEntries renders entries with the IP column padded to the
+// widest address.
+func formatHostEntries(entries []hostEntry) string {
+	width := 0
+	for _, e := range entries {
+		if len(e.IP) > width {
+			width = len(e.IP)
+		}
+	}
+
+	var sb strings.Builder
+	for _, e := range entries {
+		fmt.Fprintf(&sb, "%-*s  %s\n", width, e.IP, e.Host)
+	}
+	return sb.String()
+}
+
+// Backups of the hosts file are named <path>.ipcrawler.bak.<timestamp>,
+// e.g. /etc/hosts.ipcrawler.bak.20260307T090530.
+const (
+	hostsBackupSuffix = ".ipcrawler.bak"
+	hostsBackupTime   = "20060102T150405"
+)
+
+// HostsBackup is a saved copy of the hosts file.
+type HostsBackup struct {
+	Path string
+	Time time.Time
+}
+
+// RestoreHosts removes the ipcrawler block from the hosts file at path,
+// saving the current contents to a timestamped backup first. Returns the
+// number of lines removed and the backup path; 0 means the file had no
+// ipcrawler entries and was left untouched.
+func RestoreHosts(path string) (int, string, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return 0, "", err
+	}
+	restored, removed := removeHostsBlock(string(data))
+	if removed == 0 {
+		return 0, "", nil
+	}
+
+	backup, err := backupHosts(path, data, time.Now())
+	if err != nil {
+		return 0, "", err
+	}
+	if err := writeInPlace(path, []byte(restored)); err != nil {
+		return 0, backup, err
+	}
+	return removed, backup, nil
+}
+
+// RestoreHostsBackup replaces the hosts file at path with the contents of
+// backup, after checking that backup looks like a hosts file and saving
+// the current file to a fresh timestamped backup. Returns that backup.
+func RestoreHostsBackup(path, backup string) (string, error) {
+	data, err := readHostsBackup(backup)
+	if err != nil {
+		return "", err
+	}
+
+	current, err := os.ReadFile(path)
+	if err != nil {
+		return "", err
+	}
+	safety, err := backupHosts(path, current, time.Now())
+	if err != nil {
+		return "", err
+	}
+	if err := writeInPlace(path, data); err != nil {
+		return safety, err
+	}
+	return safety, nil
+}
+
+// HostsEntries returns the number of lines RestoreHosts would remove from
+// the hosts file at path.
+func HostsEntries(path string) (int, error) {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return 0, err
+	}
+	_, removed := removeHostsBlock(string(data))
+	return removed, nil
+}
+
+// CheckHostsBackup reports whether backup can be restored: it must be
+// readable and look like a hosts file.
+func CheckHostsBackup(backup string) error {
+	_, err := readHostsBackup(backup)
+	return err
+}
+
+func readHostsBackup(backup string) ([]byte, error) {
+	data, err := os.ReadFile(backup)
+	if err != nil {
+		return nil, err
+	}
+	if err := validateHostsFile(data); err != nil {
+		return nil, fmt.Errorf("%s: %w", backup, err)
+	}
+	return data, nil
+}
+
+// ListHostsBackups returns the backups of the hosts file at path, newest
+// first. Timestamps come from the file name; the untimestamped backup
+// older versions wrote is dated by its modification time.
+func ListHostsBackups(path string) ([]HostsBackup, error) {
+	entries, err := os.ReadDir(filepath.Dir(path))
+	if err != nil {
+		return nil, err
+	}
+	prefix := filepath.Base(path) + hostsBackupSuffix
+
+	var backups []HostsBackup
+	for _, e := range entries {
+		name := e.Name()
+		if e.IsDir() || !strings.HasPrefix(name, prefix) {
+			continue
+		}
+		b := HostsBackup{Path: filepath.Join(filepath.Dir(path), name)}
+		switch stamp := strings.TrimPrefix(name, prefix); {
+		case stamp == "":
+			info, err := e.Info()
+			if err != nil {
+				continue
+			}
+			b.Time = info.ModTime()
+		case strings.HasPrefix(stamp, "."):
+			t, err := time.ParseInLocation(hostsBackupTime, strings.TrimPrefix(stamp, "."), time.Local)
+			if err != nil {
+				continue
+			}
+			b.Time = t
+		default:
+			continue
+		}
+		backups = append(backups, b)
+	}
+	sort.SliceStable(backups, func(i, j int) bool { return backups[i].Time.After(backups[j].Time) })
+	return backups, nil
+}
+
+// backupHosts writes data to path.ipcrawler.bak.<timestamp>. O_EXCL keeps
+// an earlier backup from being overwritten; a second backup within the
+// same second waits for the next timestamp.
+func backupHosts(path string, data []byte, now time.Time) (string, error) {
+	for {
+		backup := path + hostsBackupSuffix + "." + now.Format(hostsBackupTime)
+		f, err := os.OpenFile(backup, os.O_WRONLY|os.O_CREATE|os.O_EXCL, 0644)
+		if errors.Is(err, fs.ErrExist) {
+			now = now.Add(time.Second)
+			continue
+		}
+		if err != nil {
+			return "", fmt.Errorf("back up %s: %w", path, err)
+		}
+		if _, err := f.Write(data); err != nil {
+			_ = f.Close()
+			return "", fmt.Errorf("back up %s: %w", path, err)
+		}
+		if err := f.Close(); err != nil {
+			return "", fmt.Errorf("back up %s: %w", path, err)
+		}
+		return backup, nil
+	}
+}
+
+// writeInPlace truncates and rewrites path without replacing the file, so
+// its owner, mode, and SELinux label survive and a bind-mounted
+// /etc/hosts (as in containers) can still be written.
+func writeInPlace(path string, data []byte) error {
+	f, err := os.OpenFile(path, os.O_WRONLY|os.O_TRUNC, 0)
+	if err != nil {
+		return err
+	}
+	if _, err := f.Write(data); err != nil {
+		_ = f.Close()
+		return fmt.Errorf("write %s: %w", path, err)
+	}
+	if err := f.Sync(); err != nil {
+		_ = f.Close()
+		return fmt.Errorf("sync %s: %w", path, err)
+	}
+	return f.Close()
+}
+
+// validateHostsFile checks that data looks like a hosts file: text whose
+// every non-comment line is an IP address followed by hostnames.
+func validateHostsFile(data []byte) error {
+	if len(bytes.TrimSpace(data)) == 0 {
+		return errors.New("file is empty")
+	}
+	if bytes.IndexByte(data, 0) >= 0 {
+		return errors.New("file is not text")
+	}
+	for i, line := range strings.Split(string(data), "\n") {
+		if j := strings.Index(line, "#"); j >= 0 {
+			line = line[:j]
+		}
+		fields := strings.Fields(line)
+		if len(fields) == 0 {
+			continue
+		}
+		if net.ParseIP(fields[0]) == nil || len(fields) < 2 {
+			return fmt.Errorf("line %d is not an \"IP hostname\" entry", i+1)
+		}
+	}
+	return nil
+}
+
+// removeHostsBlock strips every ipcrawler marker block, markers included,
+// and returns the remaining content with the number of lines removed. A
+// START without a matching END is left alone rather than deleting to EOF.
+func removeHostsBlock(content string) (string, int) {
+	lines := strings.SplitAfter(content, "\n")
+	var out []string
+	removed := 0
+	for i := 0; i < len(lines); i++ {
+		if strings.TrimSpace(lines[i]) != hostsBlockStart {
+			out = append(out, lines[i])
+			continue
+		}
+		end := -1
+		for j := i + 1; j < len(lines); j++ {
+			if strings.TrimSpace(lines[j]) == hostsBlockEnd {
+				end = j
+				break
+			}
+		}
+		if end < 0 {
+			out = append(out, lines[i:]...)
+			break
+		}
+		removed += end - i + 1
+		i = end
+	}
+	return strings.Join(out, ""), removed
+}
@@ -0,0 +1,284 @@
+package report
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+)
+
+func TestWriteHostsAdditions(t *testing.T) {
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "raw"), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	resolved := "10.10.11.5 app.example.com\n" +
+		"10.10.11.5 app.example.com\n" + // duplicate from a second recon tool
+		"\n" +
+		"# comment\n" +
+		"not-an-ip garbage.example.com\n" +
+		"10.10.11.5 API.example.com.\n" +
+		"2001:db8::10 v6.example.com\n"
+	if err := os.WriteFile(filepath.Join(dir, "raw", "dns_resolved.txt"), []byte(resolved), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	n, err := WriteHostsAdditions(dir)
+	if err != nil {
+		t.Fatalf("WriteHostsAdditions: %v", err)
+	}
+	if n != 3 {
+		t.Errorf("wrote %d entries, want 3", n)
+	}
+
+	got, err := os.ReadFile(filepath.Join(dir, "hosts_additions.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := "10.10.11.5    app.example.com\n" +
+		"10.10.11.5    api.example.com\n" +
+		"2001:db8::10  v6.example.com\n"
+	if string(got) != want {
+		t.Errorf("hosts_additions.txt =\n%s\nwant\n%s", got, want)
+	}
+}
+
+func TestWriteHostsAdditionsNoInput(t *testing.T) {
+	dir := t.TempDir()
+	n, err := WriteHostsAdditions(dir)
+	if err != nil || n != 0 {
+		t.Fatalf("WriteHostsAdditions on empty dir = (%d, %v), want (0, nil)", n, err)
+	}
+	if _, err := os.Stat(filepath.Join(dir, "hosts_additions.txt")); !os.IsNotExist(err) {
+		t.Error("hosts_additions.txt should not be created without input")
+	}
+}
+
+func TestFilterResolvedHosts(t *testing.T) {
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "raw"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	resolved := "10.0.0.5 app.example.com\n" +
+		"10.0.0.1 gw.example.com\n" + // excluded address
+		"10.0.0.200 vpn.example.com\n" + // inside the excluded /25
+		"# comment\n" +
+		"10.0.0.130 ok.example.com\n"
+	path := filepath.Join(dir, "raw", "dns_resolved.txt")
+	if err := os.WriteFile(path, []byte(resolved), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	dropped, err := FilterResolvedHosts(dir, []string{"10.0.0.1", "10.0.0.192/26"})
+	if err != nil {
+		t.Fatalf("FilterResolvedHosts: %v", err)
+	}
+	wantDropped := []string{"10.0.0.1 gw.example.com", "10.0.0.200 vpn.example.com"}
+	if strings.Join(dropped, "|") != strings.Join(wantDropped, "|") {
+		t.Errorf("dropped = %q, want %q", dropped, wantDropped)
+	}
+
+	got, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := "10.0.0.5 app.example.com\n" +
+		"# comment\n" +
+		"10.0.0.130 ok.example.com\n"
+	if string(got) != want {
+		t.Errorf("dns_resolved.txt =\n%s\nwant\n%s", got, want)
+	}
+
+	// Nothing left to drop: the file is left alone.
+	if dropped, err := FilterResolvedHosts(dir, []string{"10.0.0.1"}); err != nil || dropped != nil {
+		t.Errorf("second FilterResolvedHosts = (%q, %v), want (nil, nil)", dropped, err)
+	}
+	if dropped, err := FilterResolvedHosts(t.TempDir(), []string{"10.0.0.1"}); err != nil || dropped != nil {
+		t.Errorf("FilterResolvedHosts without input = (%q, %v), want (nil, nil)", dropped, err)
+	}
+}
+
+func TestDedupeResolvedHosts(t *testing.T) {
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "raw"), 0755); err != nil {
+		t.Fatal(err)
+	}
+	resolved := "10.0.0.5 app.example.com\n" +
+		"10.0.0.6 api.example.com\n" +
+		"10.0.0.5  app.example.com\n" + // re-run on resume, different spacing
+		"10.0.0.6 api.example.com\n"
+	path := filepath.Join(dir, "raw", "dns_resolved.txt")
+	if err := os.WriteFile(path, []byte(resolved), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	n, err := DedupeResolvedHosts(dir)
+	if err != nil || n != 2 {
+		t.Fatalf("DedupeResolvedHosts = (%d, %v), want (2, nil)", n, err)
+	}
+	got, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := "10.0.0.5 app.example.com\n10.0.0.6 api.example.com\n"
+	if string(got) != want {
+		t.Errorf("dns_resolved.txt = %q, want %q", got, want)
+	}
+}
+
+func TestRemoveHostsBlock(t *testing.T) {
+	hosts := "127.0.0.1 localhost\n" +
+		"# ipcrawler START\n" +
+		"10.10.11.5 app.example.com\n" +
+		"# ipcrawler END\n" +
+		"192.168.1.10 nas.lan\n"
+
+	got, removed := removeHostsBlock(hosts)
+	if want := "127.0.0.1 localhost\n192.168.1.10 nas.lan\n"; got != want {
+		t.Errorf("removeHostsBlock = %q, want %q", got, want)
+	}
+	if removed != 3 {
+		t.Errorf("removed = %d, want 3", removed)
+	}
+
+	// An unterminated block must not swallow the rest of the file.
+	partial := "127.0.0.1 localhost\n# ipcrawler START\n192.168.1.10 nas.lan\n"
+	if got, removed := removeHostsBlock(partial); got != partial || removed != 0 {
+		t.Errorf("unterminated block: got %q (%d removed), want input unchanged", got, removed)
+	}
+}
+
+func TestRestoreHosts(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "hosts")
+	original := "127.0.0.1 localhost\n# ipcrawler START\n10.10.11.5 app.example.com\n# ipcrawler END\n"
+	if err := os.WriteFile(path, []byte(original), 0600); err != nil {
+		t.Fatal(err)
+	}
+	before, err := os.Stat(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	n, backup, err := RestoreHosts(path)
+	if err != nil || n != 3 {
+		t.Fatalf("RestoreHosts = %d, %v; want 3, nil", n, err)
+	}
+	if got, _ := os.ReadFile(path); string(got) != "127.0.0.1 localhost\n" {
+		t.Errorf("hosts = %q, want ipcrawler block removed", got)
+	}
+	if got, _ := os.ReadFile(backup); string(got) != original {
+		t.Errorf("backup = %q, want original contents", got)
+	}
+
+	// Rewritten in place: same file, same mode.
+	after, err := os.Stat(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !os.SameFile(before, after) {
+		t.Error("hosts file was replaced instead of rewritten in place")
+	}
+	if after.Mode().Perm() != 0600 {
+		t.Errorf("mode = %v, want 0600 kept", after.Mode().Perm())
+	}
+
+	// A second run finds nothing and leaves the backup alone.
+	if n, _, err := RestoreHosts(path); err != nil || n != 0 {
+		t.Errorf("second RestoreHosts = %d, %v; want 0, nil", n, err)
+	}
+	if got, _ := os.ReadFile(backup); string(got) != original {
+		t.Errorf("backup = %q after second run, want original contents", got)
+	}
+}
+
+func TestBackupHostsNeverOverwrites(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "hosts")
+	now := time.Date(2026, 3, 7, 9, 5, 30, 0, time.Local)
+
+	first, err := backupHosts(path, []byte("first\n"), now)
+	if err != nil {
+		t.Fatal(err)
+	}
+	second, err := backupHosts(path, []byte("second\n"), now)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if first == second {
+		t.Fatalf("both backups went to %s", first)
+	}
+	if got, _ := os.ReadFile(first); string(got) != "first\n" {
+		t.Errorf("first backup = %q, want it untouched", got)
+	}
+}
+
+func TestListHostsBackups(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "hosts")
+	files := []string{
+		"hosts.ipcrawler.bak.20260305T120000",
+		"hosts.ipcrawler.bak.20260307T090530",
+		"hosts.ipcrawler.bak.20260101T000000",
+		"hosts.ipcrawler.bak.garbage",
+		"hosts.bak",
+		"hosts",
+	}
+	for _, name := range files {
+		if err := os.WriteFile(filepath.Join(dir, name), []byte("127.0.0.1 localhost\n"), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	backups, err := ListHostsBackups(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var got []string
+	for _, b := range backups {
+		got = append(got, filepath.Base(b.Path))
+	}
+	want := []string{
+		"hosts.ipcrawler.bak.20260307T090530",
+		"hosts.ipcrawler.bak.20260305T120000",
+		"hosts.ipcrawler.bak.20260101T000000",
+	}
+	if strings.Join(got, ",") != strings.Join(want, ",") {
+		t.Errorf("backups = %v, want %v", got, want)
+	}
+}
+
+func TestRestoreHostsBackup(t *testing.T) {
+	dir := t.TempDir()
+	path := filepath.Join(dir, "hosts")
+	current := "127.0.0.1 localhost\n10.10.11.5 app.example.com\n"
+	if err := os.WriteFile(path, []byte(current), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	bad := filepath.Join(dir, "not-hosts")
+	if err := os.WriteFile(bad, []byte("#!/bin/sh\nrm -rf /\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := RestoreHostsBackup(path, bad); err == nil {
+		t.Error("restored a file that is not a hosts file")
+	}
+	if got, _ := os.ReadFile(path); string(got) != current {
+		t.Errorf("hosts = %q after rejected restore, want unchanged", got)
+	}
+
+	good := filepath.Join(dir, "hosts.ipcrawler.bak.20260307T090530")
+	if err := os.WriteFile(good, []byte("# static\n127.0.0.1 localhost\n::1 localhost\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	safety, err := RestoreHostsBackup(path, good)
+	if err != nil {
+		t.Fatalf("RestoreHostsBackup: %v", err)
+	}
+	if got, _ := os.ReadFile(path); string(got) != "# static\n127.0.0.1 localhost\n::1 localhost\n" {
+		t.Errorf("hosts = %q, want backup contents", got)
+	}
+	if got, _ := os.ReadFile(safety); string(got) != current {
+		t.Errorf("safety backup = %q, want previous contents", got)
+	}
+}
@@ -0,0 +1,59 @@
+package report
+
+import (
+	"encoding/json"
+	"io"
+	"path/filepath"
+
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+)
+
+// jsonResult is the machine-readable record for one tool in results.json.
+// It carries the exact command that was executed so any single run can be
+// audited or reproduced later.
+type jsonResult struct {
+	Name      string `json:"name"`
+	Category  string `json:"category"`
+	Command   string `json:"command"`
+	Status    string `json:"status"`
+	Duration  string `json:"duration"`
+	Error     string `json:"error,omitempty"`
+	Truncated bool   `json:"truncated,omitempty"`
+	RawPath   string `json:"raw_path,omitempty"`
+}
+
+type jsonReport struct {
+	Target      string       `json:"target"`
+	Date        string       `json:"date"`
+	Interrupted bool         `json:"interrupted,omitempty"`
+	Results     []jsonResult `json:"results"`
+}
+
+// WriteJSON writes {outputDir}/results.json with one entry per tool.
+// Output bodies are left in raw/ and referenced by path.
+func WriteJSON(outputDir string, data ReportData) error {
+	out := jsonReport{
+		Target:      data.Target,
+		Date:        data.Date,
+		Interrupted: data.Interrupted,
+		Results:     make([]jsonResult, 0, len(data.Results)),
+	}
+	for _, r := range data.Results {
+		out.Results = append(out.Results, jsonResult{
+			Name:      r.Name,
+			Category:  r.Category,
+			Command:   r.Command,
+			Status:    r.Status,
+			Duration:  r.Duration,
+			Error:     r.Error,
+			Truncated: r.Truncated,
+			RawPath:   filepath.ToSlash(r.RawPath),
+		})
+	}
+
+	return fsutil.WriteAtomic(filepath.Join(outputDir, "results.json"), func(w io.Writer) error {
+		enc := json.NewEncoder(w)
+		enc.SetIndent("", "  ")
+		return enc.Encode(out)
+	})
+}
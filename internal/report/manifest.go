@@ -0,0 +1,139 @@
+package report
+
+import (
+	"encoding/json"
+	"io"
+	"io/fs"
+	"path/filepath"
+	"sort"
+	"strings"
+	"time"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+)
+
+// Artifact is a single file produced during a scan.
+type Artifact struct {
+	Path  string   `json:"path"` // relative to the scan directory
+	Type  string   `json:"type"` // "stdout", "stderr", "raw", "log", or "report"
+	Size  int64    `json:"size"`
+	Tools []string `json:"tools,omitempty"`
+}
+
+// Manifest indexes every artifact in a scan directory.
+type Manifest struct {
+	Target    string     `json:"target"`
+	Generated string     `json:"generated"`
+	Artifacts []Artifact `json:"artifacts"`
+}
+
+// WriteManifest walks outputDir and writes {outputDir}/manifest.json
+// listing every file with its type, size, and the tools that produced it.
+// commands maps tool name → resolved command; a file under raw/ is
+// attributed to every tool whose command references its path, so shared
+// files like dns_resolved.txt list all contributing tools.
+func WriteManifest(outputDir, target string, commands map[string]string) error {
+	names := make([]string, 0, len(commands))
+	for name := range commands {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+
+	var artifacts []Artifact
+	err := filepath.WalkDir(outputDir, func(path string, d fs.DirEntry, err error) error {
+		if err != nil {
+			return err
+		}
+		if d.IsDir() {
+			return nil
+		}
+		rel, err := filepath.Rel(outputDir, path)
+		if err != nil {
+			return err
+		}
+		base := d.Name()
+		if rel == "manifest.json" || strings.HasPrefix(base, ".") {
+			return nil
+		}
+		info, err := d.Info()
+		if err != nil {
+			return err
+		}
+
+		a := Artifact{Path: filepath.ToSlash(rel), Size: info.Size()}
+		switch filepath.Dir(rel) {
+		case "raw":
+			a.Type = "raw"
+			a.Tools = rawOwners(outputDir, base, names, commands)
+			for _, name := range names {
+				if base == config.SanitizeName(name)+".txt" {
+					a.Type = "stdout"
+					a.Tools = []string{name}
+				}
+			}
+		case "errors":
+			a.Type = "stderr"
+			for _, name := range names {
+				if base == config.SanitizeName(name)+"_err.txt" {
+					a.Tools = []string{name}
+				}
+			}
+		case "logs":
+			a.Type = "log"
+		default:
+			a.Type = "report"
+		}
+		artifacts = append(artifacts, a)
+		return nil
+	})
+	if err != nil {
+		return err
+	}
+
+	sort.Slice(artifacts, func(i, j int) bool { return artifacts[i].Path < artifacts[j].Path })
+
+	m := Manifest{
+		Target:    target,
+		Generated: time.Now().Format(time.RFC3339),
+		Artifacts: artifacts,
+	}
+	return fsutil.WriteAtomic(filepath.Join(outputDir, "manifest.json"), func(w io.Writer) error {
+		enc := json.NewEncoder(w)
+		enc.SetIndent("", "  ")
+		return enc.Encode(m)
+	})
+}
+
+// rawOwners returns the tools whose command references raw/<stem>, where
+// stem is the file name without its extension (so -oA raw/nmap claims
+// nmap.xml, nmap.nmap, and nmap.gnmap).
+func rawOwners(outputDir, base string, names []string, commands map[string]string) []string {
+	ext := filepath.Ext(base)
+	ref := filepath.Join(outputDir, "raw", strings.TrimSuffix(base, ext))
+
+	var owners []string
+	for _, name := range names {
+		if referencesPath(commands[name], ref, ext) {
+			owners = append(owners, name)
+		}
+	}
+	return owners
+}
+
+// referencesPath reports whether command mentions ref as a whole path,
+// optionally followed by ext. A bare prefix match is not enough:
+// raw/nmap must not claim the files of -oA raw/nmap_udp.
+func referencesPath(command, ref, ext string) bool {
+	for i := 0; ; {
+		j := strings.Index(command[i:], ref)
+		if j < 0 {
+			return false
+		}
+		rest := strings.TrimPrefix(command[i+j+len(ref):], ext)
+		if rest == "" || strings.ContainsRune(" \t\n'\";|&)<>", rune(rest[0])) {
+			return true
+		}
+		i += j + 1
+	}
+}
@@ -0,0 +1,117 @@
+package report
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestWriteManifest(t *testing.T) {
+	dir := t.TempDir()
+	files := map[string]string{
+		"raw/nmap_sv_scan.txt":        "stdout",
+		"raw/nmap.xml":                "<nmaprun/>",
+		"raw/dns_resolved.txt":        "10.0.0.1 a.example.com\n",
+		"errors/nmap_sv_scan_err.txt": "",
+		"logs/engine.log":             "started\n",
+		"report.md":                   "# IPCrawler Report\n",
+		"raw/.dns_resolved.txt.1.tmp": "leftover",
+		"raw/hakrevdns_rdns.txt":      "a.example.com\n",
+		"raw/subdomain_resolver.txt":  "",
+	}
+	for rel, body := range files {
+		path := filepath.Join(dir, rel)
+		if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+			t.Fatal(err)
+		}
+		if err := os.WriteFile(path, []byte(body), 0644); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	raw := filepath.Join(dir, "raw")
+	commands := map[string]string{
+		"Nmap SV Scan":       "nmap -sV -oA " + raw + "/nmap 10.0.0.1",
+		"Hakrevdns rDNS":     "echo 10.0.0.1 | hakrevdns -d >> " + raw + "/dns_resolved.txt",
+		"Subdomain Resolver": "subfinder -d x | dnsx >> " + raw + "/dns_resolved.txt",
+	}
+	if err := WriteManifest(dir, "10.0.0.1", commands); err != nil {
+		t.Fatalf("WriteManifest: %v", err)
+	}
+
+	data, err := os.ReadFile(filepath.Join(dir, "manifest.json"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	var m Manifest
+	if err := json.Unmarshal(data, &m); err != nil {
+		t.Fatalf("manifest.json is not valid JSON: %v", err)
+	}
+
+	byPath := map[string]Artifact{}
+	for _, a := range m.Artifacts {
+		byPath[a.Path] = a
+	}
+	if len(byPath) != 8 {
+		t.Errorf("expected 8 artifacts (temp file skipped), got %d", len(byPath))
+	}
+
+	checks := []struct {
+		path  string
+		typ   string
+		tools []string
+	}{
+		{"raw/nmap_sv_scan.txt", "stdout", []string{"Nmap SV Scan"}},
+		{"raw/nmap.xml", "raw", []string{"Nmap SV Scan"}},
+		{"raw/dns_resolved.txt", "raw", []string{"Hakrevdns rDNS", "Subdomain Resolver"}},
+		{"errors/nmap_sv_scan_err.txt", "stderr", []string{"Nmap SV Scan"}},
+		{"logs/engine.log", "log", nil},
+		{"report.md", "report", nil},
+	}
+	for _, c := range checks {
+		a, ok := byPath[c.path]
+		if !ok {
+			t.Errorf("missing artifact %s", c.path)
+			continue
+		}
+		if a.Type != c.typ {
+			t.Errorf("%s type = %q, want %q", c.path, a.Type, c.typ)
+		}
+		if len(a.Tools) != len(c.tools) {
+			t.Errorf("%s tools = %v, want %v", c.path, a.Tools, c.tools)
+			continue
+		}
+		for i := range c.tools {
+			if a.Tools[i] != c.tools[i] {
+				t.Errorf("%s tools = %v, want %v", c.path, a.Tools, c.tools)
+				break
+			}
+		}
+	}
+}
+
+func TestRawOwnersNeedsWholeStem(t *testing.T) {
+	dir := t.TempDir()
+	raw := filepath.Join(dir, "raw")
+	names := []string{"Nmap SV Scan", "Nmap UDP Scan", "Nmap XML Only"}
+	commands := map[string]string{
+		"Nmap SV Scan":  "sudo nmap -sS -oA " + raw + "/nmap 10.0.0.1",
+		"Nmap UDP Scan": "sudo nmap -sU -oA " + raw + "/nmap_udp 10.0.0.1",
+		"Nmap XML Only": "nmap -oX " + raw + "/nmap.xml 10.0.0.1",
+	}
+
+	tests := map[string][]string{
+		"nmap.xml":       {"Nmap SV Scan", "Nmap XML Only"},
+		"nmap.gnmap":     {"Nmap SV Scan"},
+		"nmap_udp.xml":   {"Nmap UDP Scan"},
+		"nmap_udp.gnmap": {"Nmap UDP Scan"},
+	}
+	for base, want := range tests {
+		got := rawOwners(dir, base, names, commands)
+		if strings.Join(got, ",") != strings.Join(want, ",") {
+			t.Errorf("rawOwners(%s) = %v, want %v", base, got, want)
+		}
+	}
+}
@@ -5,11 +5,13 @@ import (
 	_ "embed"
 	"fmt"
 	"io"
-	"os"
 	"path/filepath"
+	"strings"
 	"text/template"
 
 	"github.com/vdjagilev/nmap-formatter/v3/formatter"
+
+	"github.com/neur0map/ipcrawler/internal/fsutil"
 )
 
 //go:embed report.tmpl
@@ -25,20 +27,39 @@ type ToolResult struct {
 	Duration    string // formatted duration
 	Output      string // stdout content
 	Stderr      string // stderr content
+	Error       string // runner error: exit status, kill reason, or skip reason
 	Failed      bool
-	IsFormatted bool // true = Output is already markdown, skip code block wrapping
+	IsFormatted bool   // true = Output is already markdown, skip code block wrapping
+	Truncated   bool   // capture hit the template's max_output_mb cap
+	Omitted     int    // output lines left out of the report (see RawPath)
+	RawPath     string // raw capture path relative to the scan directory
+}
+
+// MaxOutputLines caps how much of a tool's output is embedded in
+// report.md. The full capture always stays in raw/.
+const MaxOutputLines = 500
+
+// CapLines keeps the first max lines of s and returns the number of
+// lines dropped.
+func CapLines(s string, max int) (string, int) {
+	lines := strings.Split(s, "\n")
+	if len(lines) <= max {
+		return s, 0
+	}
+	return strings.Join(lines[:max], "\n"), len(lines) - max
 }
 
 // ReportData is the top-level structure passed to the report template.
 type ReportData struct {
-	Target  string
-	Date    string
-	Results []ToolResult
+	Target      string
+	Date        string
+	Interrupted bool // the user cancelled before every tool finished
+	Results     []ToolResult
 }
 
 // Compile executes the embedded report template against data and writes
-// the result to {outputDir}/report.md.
-func Compile(outputDir string, data ReportData) (err error) {
+// the result to {outputDir}/report.md. The file is replaced atomically.
+func Compile(outputDir string, data ReportData) error {
 	funcMap := template.FuncMap{
 		"codeBlock": func(s string) string {
 			return "```\n" + s + "\n```"
@@ -51,21 +72,27 @@ func Compile(outputDir string, data ReportData) (err error) {
 	}
 
 	reportPath := filepath.Join(outputDir, "report.md")
-	f, err := os.Create(reportPath)
-	if err != nil {
-		return fmt.Errorf("create report: %w", err)
-	}
-	defer func() {
-		if cerr := f.Close(); cerr != nil && err == nil {
-			err = fmt.Errorf("close report: %w", cerr)
+	return fsutil.WriteAtomic(reportPath, func(w io.Writer) error {
+		if err := tmpl.Execute(w, data); err != nil {
+			return fmt.Errorf("execute template: %w", err)
 		}
-	}()
+		return nil
+	})
+}
 
-	if err = tmpl.Execute(f, data); err != nil {
-		return fmt.Errorf("execute template: %w", err)
+// NmapXMLPath returns the XML file an nmap command writes via -oA or -oX,
+// or "" if the command writes none.
+func NmapXMLPath(command string) string {
+	fields := strings.Fields(command)
+	for i := 0; i+1 < len(fields); i++ {
+		switch fields[i] {
+		case "-oA":
+			return fields[i+1] + ".xml"
+		case "-oX":
+			return fields[i+1]
+		}
 	}
-
-	return nil
+	return ""
 }
 
 // nopWriteCloser wraps an io.Writer into an io.WriteCloser with a no-op Close.
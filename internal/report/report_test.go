@@ -0,0 +1,122 @@
+package report
+
+import (
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestCapLines(t *testing.T) {
+	out, omitted := CapLines("a\nb\nc\nd", 2)
+	if out != "a\nb" || omitted != 2 {
+		t.Errorf("CapLines = (%q, %d), want (\"a\\nb\", 2)", out, omitted)
+	}
+
+	out, omitted = CapLines("a\nb", 2)
+	if out != "a\nb" || omitted != 0 {
+		t.Errorf("CapLines under limit = (%q, %d), want unchanged", out, omitted)
+	}
+}
+
+func TestWriteJSONIncludesCommands(t *testing.T) {
+	dir := t.TempDir()
+	data := ReportData{
+		Target: "10.0.0.1",
+		Date:   "2026-01-01 00:00:00",
+		Results: []ToolResult{
+			{Name: "Nmap SV Scan", Category: "NETWORK", Command: "sudo nmap -sS -sV -Pn -p 22,80 10.0.0.1", Status: "Success", Duration: "12.3s", RawPath: "raw/nmap_sv_scan.txt"},
+			{Name: "Hosts Updater", Category: "RECON", Command: "sh -c 'true'", Status: "Skipped", Error: "skipped: dependency \"Subdomain Resolver\" failed"},
+		},
+	}
+	if err := WriteJSON(dir, data); err != nil {
+		t.Fatalf("WriteJSON: %v", err)
+	}
+
+	raw, err := os.ReadFile(filepath.Join(dir, "results.json"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	var got jsonReport
+	if err := json.Unmarshal(raw, &got); err != nil {
+		t.Fatalf("results.json is not valid JSON: %v", err)
+	}
+	if len(got.Results) != 2 {
+		t.Fatalf("expected 2 results, got %d", len(got.Results))
+	}
+	if got.Results[0].Command != data.Results[0].Command {
+		t.Errorf("command = %q, want %q", got.Results[0].Command, data.Results[0].Command)
+	}
+	if got.Results[1].Error == "" || got.Results[1].Status != "Skipped" {
+		t.Errorf("skipped result = %+v", got.Results[1])
+	}
+}
+
+func TestCompileWritesReport(t *testing.T) {
+	dir := t.TempDir()
+	data := ReportData{
+		Target: "10.0.0.1",
+		Date:   "2026-01-01 00:00:00",
+		Results: []ToolResult{
+			{Name: "Ping", Category: "NETWORK", Command: "ping -c 4 10.0.0.1", Status: "Success", Output: "64 bytes from 10.0.0.1"},
+		},
+	}
+	if err := Compile(dir, data); err != nil {
+		t.Fatalf("Compile: %v", err)
+	}
+
+	got, err := os.ReadFile(filepath.Join(dir, "report.md"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if !strings.Contains(string(got), "64 bytes from 10.0.0.1") {
+		t.Errorf("report missing tool output:\n%s", got)
+	}
+
+	entries, _ := os.ReadDir(dir)
+	for _, e := range entries {
+		if strings.HasSuffix(e.Name(), ".tmp") {
+			t.Errorf("leftover temp file %s", e.Name())
+		}
+	}
+}
+
+func TestCompilePartialBanner(t *testing.T) {
+	dir := t.TempDir()
+	data := ReportData{Target: "10.0.0.1", Date: "2026-01-01 00:00:00"}
+	if err := Compile(dir, data); err != nil {
+		t.Fatal(err)
+	}
+	got, _ := os.ReadFile(filepath.Join(dir, "report.md"))
+	if strings.Contains(string(got), "Partial results") {
+		t.Error("complete scan should not carry the partial results banner")
+	}
+
+	data.Interrupted = true
+	if err := Compile(dir, data); err != nil {
+		t.Fatal(err)
+	}
+	got, _ = os.ReadFile(filepath.Join(dir, "report.md"))
+	if !strings.Contains(string(got), "Partial results") {
+		t.Errorf("interrupted scan missing partial results banner:\n%s", got)
+	}
+}
+
+func TestNmapXMLPath(t *testing.T) {
+	tests := []struct {
+		command string
+		want    string
+	}{
+		{"nmap -sS -oA scans/x/raw/nmap 10.0.0.1", "scans/x/raw/nmap.xml"},
+		{"sudo nmap -sU -oA scans/x/raw/nmap_udp 10.0.0.1", "scans/x/raw/nmap_udp.xml"},
+		{"nmap -oX out.xml 10.0.0.1", "out.xml"},
+		{"nmap 10.0.0.1", ""},
+		{"nmap -oA", ""},
+	}
+	for _, tt := range tests {
+		if got := NmapXMLPath(tt.command); got != tt.want {
+			t.Errorf("NmapXMLPath(%q) = %q, want %q", tt.command, got, tt.want)
+		}
+	}
+}
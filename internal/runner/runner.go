@@ -3,16 +3,20 @@ package runner
 import (
 	"bufio"
 	"context"
+	"encoding/json"
+	"errors"
 	"fmt"
 	"os"
 	"os/exec"
 	"path/filepath"
 	"sort"
+	"strings"
 	"sync"
 	"syscall"
 	"time"
 
 	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/report"
 	"github.com/neur0map/ipcrawler/internal/wizard"
 )
 
@@ -26,8 +30,27 @@ const (
 	StatusDone
 	StatusFailed
 	StatusSkipped
+	StatusTimedOut // killed after exceeding the template's timeout
 )
 
+var statusNames = [...]string{
+	StatusPending:  "pending",
+	StatusWaiting:  "waiting",
+	StatusRunning:  "running",
+	StatusDone:     "done",
+	StatusFailed:   "failed",
+	StatusSkipped:  "skipped",
+	StatusTimedOut: "timed_out",
+}
+
+// String returns the lowercase status name used in state.json and events.jsonl.
+func (s JobStatus) String() string {
+	if s < 0 || int(s) >= len(statusNames) {
+		return fmt.Sprintf("JobStatus(%d)", int(s))
+	}
+	return statusNames[s]
+}
+
 // Stream identifies the source of a line update.
 type Stream int
 
@@ -50,10 +73,11 @@ type JobUpdate struct {
 
 // JobResult captures the final outcome of a tool execution.
 type JobResult struct {
-	ToolName string
-	Status   JobStatus
-	Duration time.Duration
-	Err      error
+	ToolName  string
+	Status    JobStatus
+	Duration  time.Duration
+	Err       error
+	Truncated bool // stdout or stderr exceeded the template's capture cap
 }
 
 // job is an internal representation of a single tool to execute.
@@ -64,13 +88,20 @@ type job struct {
 
 // Runner manages concurrent execution of tools via a worker pool.
 type Runner struct {
-	jobs      []job
-	workers   int
-	outputDir string
-	logFile   *os.File
-	Updates   chan JobUpdate
-	results   []JobResult
-	mu        sync.Mutex
+	jobs       []job
+	workers    int
+	target     string
+	outputDir  string
+	exclude    []string // out-of-scope IPs/CIDRs
+	logFile    *os.File
+	eventsFile *os.File
+	eventsMu   sync.Mutex
+	Updates    chan JobUpdate
+	results    []JobResult
+	mu         sync.Mutex
+
+	checkpointMu  sync.Mutex
+	checkpointSeq int // number of results in the last state.json written
 }
 
 // New creates a Runner from a validated RunConfig.
@@ -86,7 +117,9 @@ func New(cfg *wizard.RunConfig) *Runner {
 	return &Runner{
 		jobs:      jobs,
 		workers:   cfg.Workers,
+		target:    cfg.Target,
 		outputDir: cfg.OutputDir,
+		exclude:   cfg.ExcludeHosts,
 		Updates:   make(chan JobUpdate, 500),
 	}
 }
@@ -100,15 +133,13 @@ func (r *Runner) Results() []JobResult {
 	return out
 }
 
-func (r *Runner) recordResult(name string, status JobStatus, duration time.Duration, err error) {
+func (r *Runner) recordResult(res JobResult) {
 	r.mu.Lock()
-	r.results = append(r.results, JobResult{
-		ToolName: name,
-		Status:   status,
-		Duration: duration,
-		Err:      err,
-	})
+	r.results = append(r.results, res)
+	seq, saved := len(r.results), r.snapshot()
 	r.mu.Unlock()
+
+	r.checkpoint(seq, saved)
 }
 
 // Execute runs all jobs concurrently, bounded by the worker pool size.
@@ -118,13 +149,21 @@ func (r *Runner) recordResult(name string, status JobStatus, duration time.Durat
 func (r *Runner) Execute(ctx context.Context) {
 	defer close(r.Updates)
 
-	// Open engine log
+	// Open engine log — appended to, so a resumed scan keeps the history
 	logPath := filepath.Join(r.outputDir, "logs", "engine.log")
-	if f, err := os.Create(logPath); err == nil {
+	if f, err := os.OpenFile(logPath, os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0644); err == nil {
 		r.logFile = f
 		defer func() { _ = f.Close() }()
 	}
 
+	// Status events stream to logs/events.jsonl as they happen, one JSON
+	// object per line, for tooling that tails the scan directory.
+	eventsPath := filepath.Join(r.outputDir, "logs", "events.jsonl")
+	if f, err := os.OpenFile(eventsPath, os.O_CREATE|os.O_WRONLY|os.O_APPEND, 0644); err == nil {
+		r.eventsFile = f
+		defer func() { _ = f.Close() }()
+	}
+
 	// Sort jobs by priority (lowest first = earliest wave)
 	sort.SliceStable(r.jobs, func(i, j int) bool {
 		return r.jobs[i].template.Priority < r.jobs[j].template.Priority
@@ -143,6 +182,17 @@ func (r *Runner) Execute(ctx context.Context) {
 		completion[j.template.Name] = make(chan struct{})
 	}
 
+	// Tools that finished in the run being resumed are done dependencies,
+	// not unselected ones.
+	for _, res := range r.Results() {
+		if _, queued := completion[res.ToolName]; !queued && res.Status == StatusDone {
+			ch := make(chan struct{})
+			close(ch)
+			completion[res.ToolName] = ch
+			finalStatus[res.ToolName] = StatusDone
+		}
+	}
+
 	var wg sync.WaitGroup
 	sem := make(chan struct{}, r.workers)
 
@@ -170,6 +220,12 @@ func (r *Runner) Execute(ctx context.Context) {
 			}
 			defer func() { <-sem }()
 
+			// Every recon tool has finished by now: tidy up the hosts they
+			// found before the tool consuming them runs.
+			if len(j.template.DependsOn) > 0 && strings.Contains(j.command, "dns_resolved.txt") {
+				r.tidyResolvedHosts()
+			}
+
 			status := r.runJob(ctx, j)
 
 			statusMu.Lock()
@@ -179,6 +235,7 @@ func (r *Runner) Execute(ctx context.Context) {
 	}
 
 	wg.Wait()
+	r.tidyResolvedHosts()
 	r.log("all jobs complete")
 }
 
@@ -219,7 +276,7 @@ func (r *Runner) waitForDeps(
 				reason := fmt.Errorf("skipped: dependency %q failed", dep)
 				r.log("skipped: %s — %v", name, reason)
 				r.send(JobUpdate{ToolName: name, Status: StatusSkipped, Err: reason})
-				r.recordResult(name, StatusSkipped, 0, reason)
+				r.recordResult(JobResult{ToolName: name, Status: StatusSkipped, Err: reason})
 
 				statusMu.Lock()
 				finalStatus[name] = StatusSkipped
@@ -234,6 +291,26 @@ func (r *Runner) waitForDeps(
 	return true
 }
 
+// tidyResolvedHosts drops repeated lines from raw/dns_resolved.txt — a
+// recon tool re-run on resume appends its entries again — and then the
+// excluded hosts, logging each one.
+func (r *Runner) tidyResolvedHosts() {
+	if n, err := report.DedupeResolvedHosts(r.outputDir); err != nil {
+		r.log("dedupe: %v", err)
+	} else if n > 0 {
+		r.log("dedupe: removed %d repeated lines from dns_resolved.txt", n)
+	}
+
+	dropped, err := report.FilterResolvedHosts(r.outputDir, r.exclude)
+	if err != nil {
+		r.log("scope: %v", err)
+		return
+	}
+	for _, line := range dropped {
+		r.log("scope: dropped out-of-scope host %q from dns_resolved.txt", line)
+	}
+}
+
 // runJob executes a single tool, capturing stdout/stderr to files
 // and sending live updates over the channel. Returns the terminal status.
 func (r *Runner) runJob(ctx context.Context, j job) JobStatus {
@@ -282,6 +359,11 @@ func (r *Runner) runJob(ctx context.Context, j job) JobStatus {
 	}
 	defer func() { _ = errFile.Close() }()
 
+	// Cap what lands on disk so a runaway tool can't fill it.
+	limit := j.template.MaxOutputBytes()
+	rawOut := &cappedFile{f: rawFile, limit: limit}
+	errOut := &cappedFile{f: errFile, limit: limit}
+
 	// Start the process
 	if err := cmd.Start(); err != nil {
 		r.fail(name, start, fmt.Errorf("start: %w", err))
@@ -299,7 +381,7 @@ func (r *Runner) runJob(ctx context.Context, j job) JobStatus {
 		scanner.Buffer(make([]byte, 256*1024), 256*1024) // 256KB line buffer
 		for scanner.Scan() {
 			line := scanner.Text()
-			_, _ = rawFile.WriteString(line + "\n")
+			rawOut.writeLine(line)
 			// Non-blocking send for line updates to avoid stalling the tool
 			r.trySend(JobUpdate{ToolName: name, Status: StatusRunning, Line: line, Stream: StreamStdout})
 		}
@@ -312,7 +394,7 @@ func (r *Runner) runJob(ctx context.Context, j job) JobStatus {
 		scanner.Buffer(make([]byte, 256*1024), 256*1024)
 		for scanner.Scan() {
 			line := scanner.Text()
-			_, _ = errFile.WriteString(line + "\n")
+			errOut.writeLine(line)
 			r.trySend(JobUpdate{ToolName: name, Status: StatusRunning, Line: line, Stream: StreamStderr})
 		}
 	}()
@@ -339,28 +421,72 @@ func (r *Runner) runJob(ctx context.Context, j job) JobStatus {
 	close(doneCh) // signal the kill goroutine to stop
 	duration := time.Since(start)
 
-	if ctx.Err() != nil {
-		// Context expired — treat as a timeout/cancellation failure
+	rawTrunc := rawOut.finish()
+	errTrunc := errOut.finish()
+	truncated := rawTrunc || errTrunc
+	if truncated {
+		r.log("truncated: %s — dropped %d stdout / %d stderr bytes past %d byte cap",
+			name, rawOut.dropped, errOut.dropped, limit)
+	}
+
+	if errors.Is(ctx.Err(), context.DeadlineExceeded) {
+		err := fmt.Errorf("timed out after %s", timeout)
+		r.log("timed out: %s (%s) — %v", name, duration.Round(time.Millisecond), err)
+		r.send(JobUpdate{ToolName: name, Status: StatusTimedOut, Err: err, Duration: duration})
+		r.recordResult(JobResult{ToolName: name, Status: StatusTimedOut, Duration: duration, Err: err, Truncated: truncated})
+		return StatusTimedOut
+	} else if ctx.Err() != nil {
+		// Scan cancelled (Ctrl+C) while the tool was running
 		err := fmt.Errorf("killed: %w", ctx.Err())
 		r.log("failed: %s (%s) — %v", name, duration.Round(time.Millisecond), err)
 		r.send(JobUpdate{ToolName: name, Status: StatusFailed, Err: err, Duration: duration})
-		r.recordResult(name, StatusFailed, duration, err)
+		r.recordResult(JobResult{ToolName: name, Status: StatusFailed, Duration: duration, Err: err, Truncated: truncated})
 		return StatusFailed
 	} else if cmdErr != nil {
 		r.log("failed: %s (%s) — %v", name, duration.Round(time.Millisecond), cmdErr)
 		r.send(JobUpdate{ToolName: name, Status: StatusFailed, Err: cmdErr, Duration: duration})
-		r.recordResult(name, StatusFailed, duration, cmdErr)
+		r.recordResult(JobResult{ToolName: name, Status: StatusFailed, Duration: duration, Err: cmdErr, Truncated: truncated})
 		return StatusFailed
 	}
 
 	r.log("completed: %s (%s)", name, duration.Round(time.Millisecond))
 	r.send(JobUpdate{ToolName: name, Status: StatusDone, Duration: duration})
-	r.recordResult(name, StatusDone, duration, nil)
+	r.recordResult(JobResult{ToolName: name, Status: StatusDone, Duration: duration, Truncated: truncated})
 	return StatusDone
 }
 
+// cappedFile writes output lines to f until limit bytes have been written,
+// then counts and drops the rest so a runaway tool can't fill the disk.
+type cappedFile struct {
+	f       *os.File
+	limit   int64
+	written int64
+	dropped int64
+}
+
+func (c *cappedFile) writeLine(line string) {
+	n := int64(len(line) + 1)
+	if c.dropped > 0 || c.written+n > c.limit {
+		c.dropped += n
+		return
+	}
+	_, _ = c.f.WriteString(line + "\n")
+	c.written += n
+}
+
+// finish appends a truncation marker if anything was dropped and
+// reports whether the stream was truncated.
+func (c *cappedFile) finish() bool {
+	if c.dropped == 0 {
+		return false
+	}
+	_, _ = fmt.Fprintf(c.f, "[... truncated %d bytes ...]\n", c.dropped)
+	return true
+}
+
 // send performs a blocking send for critical status updates.
 func (r *Runner) send(update JobUpdate) {
+	r.event(update)
 	r.Updates <- update
 }
 
@@ -379,7 +505,44 @@ func (r *Runner) fail(name string, start time.Time, err error) {
 	duration := time.Since(start)
 	r.log("failed: %s — %v", name, err)
 	r.send(JobUpdate{ToolName: name, Status: StatusFailed, Err: err, Duration: duration})
-	r.recordResult(name, StatusFailed, duration, err)
+	r.recordResult(JobResult{ToolName: name, Status: StatusFailed, Duration: duration, Err: err})
+}
+
+// event is one line of logs/events.jsonl.
+type event struct {
+	Time       time.Time `json:"time"`
+	Tool       string    `json:"tool"`
+	Target     string    `json:"target"`
+	Status     string    `json:"status"`
+	WaitingOn  string    `json:"waiting_on,omitempty"`
+	Error      string    `json:"error,omitempty"`
+	DurationMS int64     `json:"duration_ms,omitempty"`
+}
+
+// event appends a status update to the events file. Each line is written
+// with a single write call so readers never see a partial object.
+func (r *Runner) event(update JobUpdate) {
+	if r.eventsFile == nil {
+		return
+	}
+	e := event{
+		Time:       time.Now(),
+		Tool:       update.ToolName,
+		Target:     r.target,
+		Status:     update.Status.String(),
+		WaitingOn:  update.WaitingOn,
+		DurationMS: update.Duration.Milliseconds(),
+	}
+	if update.Err != nil {
+		e.Error = update.Err.Error()
+	}
+	line, err := json.Marshal(e)
+	if err != nil {
+		return
+	}
+	r.eventsMu.Lock()
+	_, _ = r.eventsFile.Write(append(line, '\n'))
+	r.eventsMu.Unlock()
 }
 
 // log writes a timestamped message to the engine log file.
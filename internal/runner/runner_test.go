@@ -0,0 +1,108 @@
+package runner
+
+import (
+	"context"
+	"encoding/json"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+	"github.com/neur0map/ipcrawler/internal/wizard"
+)
+
+func TestCappedFileTruncates(t *testing.T) {
+	f, err := os.Create(filepath.Join(t.TempDir(), "out.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	defer func() { _ = f.Close() }()
+
+	c := &cappedFile{f: f, limit: 10}
+	c.writeLine("12345") // 6 bytes
+	c.writeLine("678")   // 4 bytes — exactly at the cap
+	c.writeLine("x")     // dropped
+	c.writeLine("yz")    // dropped, even though it would not fit anyway
+
+	if !c.finish() {
+		t.Fatal("expected finish to report truncation")
+	}
+	if c.dropped != 5 {
+		t.Errorf("dropped = %d, want 5", c.dropped)
+	}
+
+	got, err := os.ReadFile(f.Name())
+	if err != nil {
+		t.Fatal(err)
+	}
+	want := "12345\n678\n[... truncated 5 bytes ...]\n"
+	if string(got) != want {
+		t.Errorf("file = %q, want %q", got, want)
+	}
+}
+
+func TestExecuteWritesEvents(t *testing.T) {
+	dir := t.TempDir()
+	if err := fsutil.MakeScanDirs(dir); err != nil {
+		t.Fatal(err)
+	}
+
+	r := New(&wizard.RunConfig{
+		Target:    "127.0.0.1",
+		Tools:     []config.Template{{Name: "Echo", Timeout: "5s"}},
+		Workers:   1,
+		OutputDir: dir,
+		Commands:  map[string]string{"Echo": "echo hi"},
+	})
+	go r.Execute(context.Background())
+	for range r.Updates {
+	}
+
+	data, err := os.ReadFile(filepath.Join(dir, "logs", "events.jsonl"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	var statuses []string
+	for _, line := range strings.Split(strings.TrimSpace(string(data)), "\n") {
+		var e map[string]any
+		if err := json.Unmarshal([]byte(line), &e); err != nil {
+			t.Fatalf("invalid events line %q: %v", line, err)
+		}
+		if e["tool"] != "Echo" || e["target"] != "127.0.0.1" {
+			t.Errorf("event = %v, want tool Echo on 127.0.0.1", e)
+		}
+		statuses = append(statuses, e["status"].(string))
+	}
+	if strings.Join(statuses, ",") != "running,done" {
+		t.Errorf("statuses = %v, want [running done]", statuses)
+	}
+}
+
+func TestExecuteTimesOut(t *testing.T) {
+	dir := t.TempDir()
+	if err := fsutil.MakeScanDirs(dir); err != nil {
+		t.Fatal(err)
+	}
+
+	r := New(&wizard.RunConfig{
+		Tools:     []config.Template{{Name: "Hung Tool", Timeout: "200ms"}},
+		Workers:   1,
+		OutputDir: dir,
+		Commands:  map[string]string{"Hung Tool": "sleep 5"},
+	})
+	start := time.Now()
+	go r.Execute(context.Background())
+	for range r.Updates {
+	}
+
+	if elapsed := time.Since(start); elapsed > 3*time.Second {
+		t.Errorf("Execute took %s, want the tool killed at its 200ms timeout", elapsed)
+	}
+	res := r.Results()
+	if len(res) != 1 || res[0].Status != StatusTimedOut {
+		t.Fatalf("results = %+v, want one StatusTimedOut", res)
+	}
+}
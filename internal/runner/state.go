@@ -0,0 +1,217 @@
+package runner
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"time"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+	"github.com/neur0map/ipcrawler/internal/wizard"
+)
+
+// runFile records the wizard's choices so an interrupted scan can be resumed.
+// stateFile is rewritten after every finished tool.
+const (
+	runFile   = "run.json"
+	stateFile = "state.json"
+)
+
+// savedRun is the on-disk form of a RunConfig. Tools are stored by name and
+// looked up again on resume for their metadata (priority, timeout,
+// depends_on), but the saved Commands are replayed exactly as resolved, so
+// edits to a template's command only affect new scans.
+type savedRun struct {
+	Target       string            `json:"target"`
+	Tools        []string          `json:"tools"`
+	Workers      int               `json:"workers"`
+	Verbose      bool              `json:"verbose"`
+	Commands     map[string]string `json:"commands"`
+	ExcludeHosts []string          `json:"exclude_hosts,omitempty"`
+}
+
+// savedResult is the on-disk form of a JobResult.
+type savedResult struct {
+	ToolName  string        `json:"tool"`
+	Status    string        `json:"status"`
+	Duration  time.Duration `json:"duration_ns"`
+	Error     string        `json:"error,omitempty"`
+	Truncated bool          `json:"truncated,omitempty"`
+}
+
+// SaveRunConfig writes {OutputDir}/run.json so the scan can later be
+// picked up with `ipcrawler resume`.
+func SaveRunConfig(cfg *wizard.RunConfig) error {
+	run := savedRun{
+		Target:       cfg.Target,
+		Workers:      cfg.Workers,
+		Verbose:      cfg.Verbose,
+		Commands:     cfg.Commands,
+		ExcludeHosts: cfg.ExcludeHosts,
+	}
+	for _, t := range cfg.Tools {
+		run.Tools = append(run.Tools, t.Name)
+	}
+	return writeJSON(filepath.Join(cfg.OutputDir, runFile), run)
+}
+
+// LoadResume rebuilds the RunConfig of the scan in dir and returns the
+// results it had checkpointed. Tools that no longer exist in templates are
+// dropped and returned by name so the caller can warn about them.
+func LoadResume(dir string, templates []config.Template) (*wizard.RunConfig, []JobResult, []string, error) {
+	var run savedRun
+	if err := readJSON(filepath.Join(dir, runFile), &run); err != nil {
+		if errors.Is(err, fs.ErrNotExist) {
+			return nil, nil, nil, fmt.Errorf("%s has no %s — not an ipcrawler scan directory", dir, runFile)
+		}
+		return nil, nil, nil, err
+	}
+
+	// No state.json just means no tool finished before the scan stopped.
+	var saved []savedResult
+	if err := readJSON(filepath.Join(dir, stateFile), &saved); err != nil && !errors.Is(err, fs.ErrNotExist) {
+		return nil, nil, nil, err
+	}
+
+	byName := make(map[string]config.Template, len(templates))
+	for _, t := range templates {
+		byName[t.Name] = t
+	}
+
+	cfg := &wizard.RunConfig{
+		Target:       run.Target,
+		Workers:      run.Workers,
+		Verbose:      run.Verbose,
+		OutputDir:    dir,
+		Commands:     run.Commands,
+		ExcludeHosts: run.ExcludeHosts,
+	}
+	var missing []string
+	for _, name := range run.Tools {
+		t, ok := byName[name]
+		if !ok {
+			missing = append(missing, name)
+			continue
+		}
+		cfg.Tools = append(cfg.Tools, t)
+	}
+
+	prev := make([]JobResult, 0, len(saved))
+	for _, s := range saved {
+		res := JobResult{ToolName: s.ToolName, Duration: s.Duration, Truncated: s.Truncated}
+		switch s.Status {
+		case "done":
+			res.Status = StatusDone
+		case "failed":
+			res.Status = StatusFailed
+		case "skipped":
+			res.Status = StatusSkipped
+		case "timed_out":
+			res.Status = StatusTimedOut
+		default:
+			continue
+		}
+		if s.Error != "" {
+			res.Err = errors.New(s.Error)
+		}
+		prev = append(prev, res)
+	}
+
+	return cfg, prev, missing, nil
+}
+
+// NewResumed creates a Runner that only executes the tools without a
+// successful result in prev. Successful results are carried over so the
+// report and state.json still cover the whole scan; failed and skipped
+// tools run again.
+func NewResumed(cfg *wizard.RunConfig, prev []JobResult) *Runner {
+	r := New(cfg)
+
+	done := make(map[string]bool)
+	for _, res := range prev {
+		if res.Status == StatusDone {
+			done[res.ToolName] = true
+			r.results = append(r.results, res)
+		}
+	}
+
+	remaining := r.jobs[:0]
+	for _, j := range r.jobs {
+		if !done[j.template.Name] {
+			remaining = append(remaining, j)
+		}
+	}
+	r.jobs = remaining
+	return r
+}
+
+// JobNames returns the names of the tools this Runner will execute.
+func (r *Runner) JobNames() []string {
+	names := make([]string, len(r.jobs))
+	for i, j := range r.jobs {
+		names[i] = j.template.Name
+	}
+	return names
+}
+
+// snapshot returns the results collected so far in their on-disk form.
+// Callers must hold r.mu.
+func (r *Runner) snapshot() []savedResult {
+	saved := make([]savedResult, 0, len(r.results))
+	for _, res := range r.results {
+		s := savedResult{
+			ToolName:  res.ToolName,
+			Status:    res.Status.String(),
+			Duration:  res.Duration,
+			Truncated: res.Truncated,
+		}
+		if res.Err != nil {
+			s.Error = res.Err.Error()
+		}
+		saved = append(saved, s)
+	}
+	return saved
+}
+
+// checkpoint rewrites state.json with saved, the snapshot of the first seq
+// results. It runs without r.mu so the fsync doesn't stall other workers;
+// a snapshot older than the one already on disk is dropped.
+func (r *Runner) checkpoint(seq int, saved []savedResult) {
+	if r.outputDir == "" {
+		return
+	}
+	r.checkpointMu.Lock()
+	defer r.checkpointMu.Unlock()
+	if seq <= r.checkpointSeq {
+		return
+	}
+	if err := writeJSON(filepath.Join(r.outputDir, stateFile), saved); err != nil {
+		r.log("checkpoint failed: %v", err)
+		return
+	}
+	r.checkpointSeq = seq
+}
+
+func writeJSON(path string, v any) error {
+	return fsutil.WriteAtomic(path, func(w io.Writer) error {
+		enc := json.NewEncoder(w)
+		enc.SetIndent("", "  ")
+		return enc.Encode(v)
+	})
+}
+
+func readJSON(path string, v any) error {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return err
+	}
+	if err := json.Unmarshal(data, v); err != nil {
+		return fmt.Errorf("parse %s: %w", path, err)
+	}
+	return nil
+}
@@ -0,0 +1,172 @@
+package runner
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+	"testing"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+	"github.com/neur0map/ipcrawler/internal/wizard"
+)
+
+func TestResumeSkipsCompletedTools(t *testing.T) {
+	dir := t.TempDir()
+	templates := []config.Template{
+		{Name: "Ping"},
+		{Name: "Nmap"},
+		{Name: "Dig"},
+	}
+	cfg := &wizard.RunConfig{
+		Target:    "10.0.0.1",
+		Tools:     templates,
+		Workers:   2,
+		OutputDir: dir,
+		Commands: map[string]string{
+			"Ping": "ping -c 1 10.0.0.1",
+			"Nmap": "nmap 10.0.0.1",
+			"Dig":  "dig 10.0.0.1",
+		},
+	}
+	if err := SaveRunConfig(cfg); err != nil {
+		t.Fatal(err)
+	}
+
+	// Simulate a run that finished Ping, failed Nmap, then was killed.
+	r := New(cfg)
+	r.recordResult(JobResult{ToolName: "Ping", Status: StatusDone})
+	r.recordResult(JobResult{ToolName: "Nmap", Status: StatusFailed, Err: errors.New("exit status 1")})
+
+	// Dig has since been removed from the templates.
+	got, prev, missing, err := LoadResume(dir, templates[:2])
+	if err != nil {
+		t.Fatal(err)
+	}
+	if got.Target != cfg.Target || got.Commands["Nmap"] != cfg.Commands["Nmap"] {
+		t.Errorf("reloaded config = %+v, want target and commands from run.json", got)
+	}
+	if len(missing) != 1 || missing[0] != "Dig" {
+		t.Errorf("missing = %v, want [Dig]", missing)
+	}
+	if len(prev) != 2 || prev[1].Err == nil || prev[1].Err.Error() != "exit status 1" {
+		t.Errorf("prev = %+v, want Ping done and Nmap failed with its error", prev)
+	}
+
+	resumed := NewResumed(got, prev)
+	if names := resumed.JobNames(); len(names) != 1 || names[0] != "Nmap" {
+		t.Errorf("JobNames() = %v, want [Nmap]", names)
+	}
+	if res := resumed.Results(); len(res) != 1 || res[0].ToolName != "Ping" {
+		t.Errorf("Results() = %+v, want the carried-over Ping result", res)
+	}
+}
+
+func TestLoadResumeNotAScanDir(t *testing.T) {
+	_, _, _, err := LoadResume(t.TempDir(), nil)
+	if err == nil {
+		t.Fatal("expected an error for a directory without run.json")
+	}
+}
+
+func TestLoadResumeWithoutState(t *testing.T) {
+	dir := t.TempDir()
+	cfg := &wizard.RunConfig{Target: "example.com", Tools: []config.Template{{Name: "Dig"}}, OutputDir: dir}
+	if err := SaveRunConfig(cfg); err != nil {
+		t.Fatal(err)
+	}
+	if _, err := os.Stat(filepath.Join(dir, stateFile)); !os.IsNotExist(err) {
+		t.Fatalf("state.json should not exist yet: %v", err)
+	}
+
+	got, prev, _, err := LoadResume(dir, cfg.Tools)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if len(prev) != 0 || len(NewResumed(got, prev).JobNames()) != 1 {
+		t.Error("a scan with no checkpoint should resume every tool")
+	}
+}
+
+func TestResumeRunsDependentsOfFinishedTools(t *testing.T) {
+	dir := t.TempDir()
+	if err := fsutil.MakeScanDirs(dir); err != nil {
+		t.Fatal(err)
+	}
+	// Recon finished in the interrupted run; its re-run sibling appended
+	// the same entry again before the scan was killed.
+	resolved := filepath.Join(dir, "raw", "dns_resolved.txt")
+	if err := os.WriteFile(resolved, []byte("10.0.0.5 app.example.com\n10.0.0.5 app.example.com\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	cfg := &wizard.RunConfig{
+		Tools: []config.Template{
+			{Name: "Recon", Timeout: "5s"},
+			{Name: "Updater", Timeout: "5s", DependsOn: []string{"Recon"}},
+		},
+		Workers:   1,
+		OutputDir: dir,
+		Commands: map[string]string{
+			"Recon":   "echo recon",
+			"Updater": "cat " + resolved,
+		},
+	}
+	r := NewResumed(cfg, []JobResult{{ToolName: "Recon", Status: StatusDone}})
+	go r.Execute(context.Background())
+	for range r.Updates {
+	}
+
+	var updater *JobResult
+	for _, res := range r.Results() {
+		if res.ToolName == "Updater" {
+			updater = &res
+		}
+	}
+	if updater == nil || updater.Status != StatusDone {
+		t.Fatalf("Updater result = %+v, want done", updater)
+	}
+
+	log, err := os.ReadFile(filepath.Join(dir, "logs", "engine.log"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if strings.Contains(string(log), "not selected") {
+		t.Errorf("finished dependency treated as unselected:\n%s", log)
+	}
+
+	got, err := os.ReadFile(resolved)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(got) != "10.0.0.5 app.example.com\n" {
+		t.Errorf("dns_resolved.txt = %q, want one entry", got)
+	}
+}
+
+func TestCheckpointKeepsEveryResult(t *testing.T) {
+	dir := t.TempDir()
+	r := New(&wizard.RunConfig{OutputDir: dir})
+
+	var wg sync.WaitGroup
+	for i := 0; i < 20; i++ {
+		wg.Add(1)
+		go func(i int) {
+			defer wg.Done()
+			r.recordResult(JobResult{ToolName: fmt.Sprintf("Tool %d", i), Status: StatusDone})
+		}(i)
+	}
+	wg.Wait()
+
+	var saved []savedResult
+	if err := readJSON(filepath.Join(dir, stateFile), &saved); err != nil {
+		t.Fatal(err)
+	}
+	if len(saved) != 20 {
+		t.Errorf("state.json has %d results, want 20", len(saved))
+	}
+}
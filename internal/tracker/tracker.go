@@ -3,6 +3,7 @@ package tracker
 import (
 	"fmt"
 	"os"
+	"regexp"
 	"strings"
 	"time"
 
@@ -64,6 +65,7 @@ type jobState struct {
 	name      string
 	status    runner.JobStatus
 	lastLine  string
+	progress  string // latest "NN%" reported by the tool, if any
 	waitingOn string // dependency name when status == StatusWaiting
 	err       error
 	duration  time.Duration
@@ -135,6 +137,9 @@ func (m Model) Update(msg tea.Msg) (tea.Model, tea.Cmd) {
 				m.jobs[i].status = msg.Status
 				if msg.Line != "" && msg.Stream == runner.StreamStdout {
 					m.jobs[i].lastLine = msg.Line
+					if p := parseProgress(msg.Line); p != "" {
+						m.jobs[i].progress = p
+					}
 				}
 				if msg.WaitingOn != "" {
 					m.jobs[i].waitingOn = msg.WaitingOn
@@ -203,10 +208,15 @@ func (m Model) View() string {
 			icon := m.spinner.View()
 			name := activeNameStyle.Render(paddedName)
 			var detail string
+			lineWidth := detailWidth
+			if j.progress != "" {
+				detail = durationStyle.Render(j.progress) + " "
+				lineWidth -= len(j.progress) + 1
+			}
 			if j.lastLine != "" {
-				detail = liveLineStyle.Render(smartTruncate(j.lastLine, detailWidth))
+				detail += liveLineStyle.Render(smartTruncate(j.lastLine, lineWidth))
 			} else {
-				detail = dimStyle.Render("starting...")
+				detail += dimStyle.Render("starting...")
 			}
 			line = fmt.Sprintf("  %s  %s  %s", icon, name, detail)
 
@@ -241,6 +251,16 @@ func (m Model) View() string {
 				line = fmt.Sprintf("  %s  %s  %s", icon, name, dur)
 			}
 
+		case runner.StatusTimedOut:
+			icon := failIconStyle.Render("⏱")
+			name := failNameStyle.Render(paddedName)
+			dur := durationStyle.Render(fmtDuration(j.duration))
+			detail := "timed out"
+			if j.err != nil {
+				detail = j.err.Error()
+			}
+			line = fmt.Sprintf("  %s  %s  %s  %s", icon, name, failNameStyle.Render(detail), dur)
+
 		case runner.StatusSkipped:
 			icon := skipIconStyle.Render("⊘")
 			name := skipNameStyle.Render(paddedName)
@@ -331,6 +351,9 @@ func RunVerbose(updates <-chan runner.JobUpdate) {
 
 		case update.Status == runner.StatusFailed:
 			logger.Error("✗ failed", "error", update.Err)
+
+		case update.Status == runner.StatusTimedOut:
+			logger.Error("⏱ timed out", "error", update.Err)
 		}
 	}
 }
@@ -340,18 +363,35 @@ func RunVerbose(updates <-chan runner.JobUpdate) {
 // smartTruncate clips a string to max visible characters.
 // If the string fits, it's returned as-is. Otherwise the tail is kept
 // with a leading "…" so the user sees the most recent/relevant part.
+// Works on runes so multibyte UTF-8 is never split mid-codepoint.
 func smartTruncate(s string, max int) string {
 	if max <= 0 {
 		return ""
 	}
-	if len(s) <= max {
+	r := []rune(s)
+	if len(r) <= max {
 		return s
 	}
 	// Keep the tail — the latest output is the most useful context
 	if max <= 3 {
-		return s[:max]
+		return string(r[:max])
+	}
+	return "…" + string(r[len(r)-(max-1):])
+}
+
+// progressPattern matches nmap's --stats-every lines, e.g.
+// "SYN Stealth Scan Timing: About 45.32% done; ETC: 12:01 (0:00:12 remaining)".
+var progressPattern = regexp.MustCompile(`About (\d+)(?:\.\d+)?% done`)
+
+// parseProgress returns the completion percentage in line as "NN%", or ""
+// if the line carries none. Each nmap phase restarts from 0%, so the
+// latest value is shown rather than the highest.
+func parseProgress(line string) string {
+	m := progressPattern.FindStringSubmatch(line)
+	if m == nil {
+		return ""
 	}
-	return "…" + s[len(s)-(max-1):]
+	return m[1] + "%"
 }
 
 func fmtDuration(d time.Duration) string {
@@ -0,0 +1,48 @@
+package tracker
+
+import (
+	"testing"
+	"unicode/utf8"
+)
+
+func TestSmartTruncate(t *testing.T) {
+	tests := []struct {
+		name string
+		in   string
+		max  int
+		want string
+	}{
+		{"fits", "200 OK", 10, "200 OK"},
+		{"ascii tail", "Discovered open port 443/tcp", 10, "…t 443/tcp"},
+		{"zero width", "anything", 0, ""},
+		{"tiny width", "héllo", 2, "hé"},
+		{"accented tail", "Títulò: café résumé", 8, "… résumé"},
+		{"emoji tail", "found 🔑 in config 🚀🚀", 6, "…ig 🚀🚀"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			got := smartTruncate(tt.in, tt.max)
+			if !utf8.ValidString(got) {
+				t.Fatalf("smartTruncate(%q, %d) produced invalid UTF-8: %q", tt.in, tt.max, got)
+			}
+			if got != tt.want {
+				t.Errorf("smartTruncate(%q, %d) = %q, want %q", tt.in, tt.max, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestParseProgress(t *testing.T) {
+	tests := map[string]string{
+		"SYN Stealth Scan Timing: About 45.32% done; ETC: 12:01 (0:00:12 remaining)":      "45%",
+		"Service scan Timing: About 100.00% done; ETC: 12:03 (0:00:00 remaining)":         "100%",
+		"Stats: 0:00:10 elapsed; 0 hosts completed (1 up), 1 undergoing SYN Stealth Scan": "",
+		"80/tcp open  http": "",
+	}
+	for line, want := range tests {
+		if got := parseProgress(line); got != want {
+			t.Errorf("parseProgress(%q) = %q, want %q", line, got, want)
+		}
+	}
+}
@@ -0,0 +1,194 @@
+package wizard
+
+import (
+	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"sort"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss"
+	"github.com/neur0map/ipcrawler/internal/config"
+)
+
+// checkLevel is the outcome of a single doctor check.
+type checkLevel int
+
+const (
+	checkPass checkLevel = iota
+	checkWarn
+	checkFail
+)
+
+// doctorCheck is one row of the doctor report.
+type doctorCheck struct {
+	level  checkLevel
+	label  string
+	detail string
+}
+
+// Doctor verifies the local environment against every embedded template
+// and prints a pass/warn/fail table. Missing tools and wordlists are
+// warnings; anything that would break every run is a failure. Returns
+// false if any check failed.
+func Doctor(templates []config.Template) bool {
+	sections := []struct {
+		title  string
+		checks []doctorCheck
+	}{
+		{"Tools", doctorTools(templates)},
+		{"Wordlists", doctorWordlists()},
+		{"Privileges", doctorPrivileges(templates)},
+		{"Filesystem", doctorFilesystem()},
+	}
+
+	passStyle := lipgloss.NewStyle().Foreground(tGreen)
+	warnStyle := lipgloss.NewStyle().Foreground(tYellow)
+	failStyle := lipgloss.NewStyle().Foreground(tRed).Bold(true)
+	hint := lipgloss.NewStyle().Foreground(tDim)
+
+	ok := true
+	var passed, warned, failed int
+	for _, sec := range sections {
+		fmt.Println(titleStyle.Render("\n  " + sec.title))
+		for _, c := range sec.checks {
+			var icon string
+			switch c.level {
+			case checkPass:
+				icon = passStyle.Render("✓")
+				passed++
+			case checkWarn:
+				icon = warnStyle.Render("!")
+				warned++
+			case checkFail:
+				icon = failStyle.Render("✗")
+				failed++
+				ok = false
+			}
+			line := fmt.Sprintf("    %s %s", icon, c.label)
+			if c.detail != "" {
+				line += hint.Render("  (" + c.detail + ")")
+			}
+			fmt.Println(line)
+		}
+	}
+
+	fmt.Printf("\n  %s · %s · %s\n\n",
+		passStyle.Render(fmt.Sprintf("%d passed", passed)),
+		warnStyle.Render(fmt.Sprintf("%d warnings", warned)),
+		failStyle.Render(fmt.Sprintf("%d failed", failed)))
+	return ok
+}
+
+// doctorTools reports which external binaries referenced by templates are
+// on PATH, along with the templates that need each one.
+func doctorTools(templates []config.Template) []doctorCheck {
+	users := map[string][]string{}
+	for _, t := range templates {
+		for _, bin := range extractBinaries(t.Command) {
+			users[bin] = append(users[bin], t.Name)
+		}
+	}
+
+	bins := make([]string, 0, len(users))
+	for b := range users {
+		bins = append(bins, b)
+	}
+	sort.Strings(bins)
+
+	checks := make([]doctorCheck, 0, len(bins))
+	for _, bin := range bins {
+		if path, err := exec.LookPath(bin); err == nil {
+			checks = append(checks, doctorCheck{checkPass, bin, path})
+			continue
+		}
+		detail := "needed by " + strings.Join(users[bin], ", ")
+		if h, ok := installHints[bin]; ok {
+			detail += " · " + h
+		}
+		checks = append(checks, doctorCheck{checkWarn, bin + " not found", detail})
+	}
+	return checks
+}
+
+// doctorWordlists checks for SecLists and each preset wordlist.
+func doctorWordlists() []doctorCheck {
+	base := findSecLists()
+	if base == "" {
+		return []doctorCheck{{checkWarn, "SecLists not found", seclistsInstallHint}}
+	}
+
+	checks := []doctorCheck{{checkPass, "SecLists", base}}
+	for _, p := range append(append([]wordlistPreset{}, dirPresets...), vhostPresets...) {
+		full := filepath.Join(base, p.RelPath)
+		if _, err := os.Stat(full); err != nil {
+			checks = append(checks, doctorCheck{checkWarn, filepath.Base(p.RelPath) + " missing", full})
+		} else {
+			checks = append(checks, doctorCheck{checkPass, filepath.Base(p.RelPath), ""})
+		}
+	}
+	return checks
+}
+
+// doctorPrivileges reports whether sudo-requiring templates can run.
+func doctorPrivileges(templates []config.Template) []doctorCheck {
+	var sudoTools []string
+	for _, t := range templates {
+		if t.Sudo || strings.Contains(t.Command, "sudo ") {
+			sudoTools = append(sudoTools, t.Name)
+		}
+	}
+
+	if os.Geteuid() == 0 {
+		return []doctorCheck{{checkPass, "running as root", ""}}
+	}
+
+	checks := []doctorCheck{{checkWarn, "not running as root",
+		"sudo will prompt once before execution"}}
+	if len(sudoTools) == 0 {
+		return checks
+	}
+	if _, err := exec.LookPath("sudo"); err != nil {
+		checks = append(checks, doctorCheck{checkWarn, "sudo not found",
+			"needed by " + strings.Join(sudoTools, ", ")})
+	} else {
+		checks = append(checks, doctorCheck{checkPass, "sudo available", ""})
+	}
+	return checks
+}
+
+// doctorFilesystem verifies the shell used to run tools exists and that
+// the scans directory and temp dir are writable.
+func doctorFilesystem() []doctorCheck {
+	var checks []doctorCheck
+
+	if _, err := exec.LookPath("sh"); err != nil {
+		checks = append(checks, doctorCheck{checkFail, "sh not found", "every tool runs via sh -c"})
+	} else {
+		checks = append(checks, doctorCheck{checkPass, "sh", ""})
+	}
+
+	for _, dir := range []string{"scans", os.TempDir()} {
+		if err := probeWritable(dir); err != nil {
+			checks = append(checks, doctorCheck{checkFail, dir + " not writable", err.Error()})
+		} else {
+			checks = append(checks, doctorCheck{checkPass, dir + " writable", ""})
+		}
+	}
+	return checks
+}
+
+// probeWritable creates dir if needed and writes/removes a scratch file.
+func probeWritable(dir string) error {
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		return err
+	}
+	f, err := os.CreateTemp(dir, ".ipcrawler-doctor-*")
+	if err != nil {
+		return err
+	}
+	name := f.Name()
+	_ = f.Close()
+	return os.Remove(name)
+}
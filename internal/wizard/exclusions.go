@@ -0,0 +1,179 @@
+package wizard
+
+import (
+	"fmt"
+	"net"
+	"regexp"
+	"strconv"
+	"strings"
+
+	"github.com/charmbracelet/huh"
+)
+
+// collectExclusions asks for out-of-scope ports and hosts. It runs
+// whatever tools are selected: the web and TLS templates hit fixed ports
+// just like nmap does, and the recon templates can turn up hosts outside
+// the target.
+func collectExclusions(theme *huh.Theme) (string, string, error) {
+	var excludePorts, excludeHosts string
+	fields := []huh.Field{
+		huh.NewInput().
+			Title("Excluded Ports").
+			Description("Out-of-scope ports no tool may touch — leave empty for none").
+			Placeholder("e.g. 3389  or  5900-5910").
+			Value(&excludePorts).
+			Validate(validateOptionalPortSpec),
+		huh.NewInput().
+			Title("Excluded Hosts").
+			Description("Out-of-scope addresses: skipped by nmap, dropped from discovered hosts — leave empty for none").
+			Placeholder("e.g. 10.0.0.1,10.0.0.128/25").
+			Value(&excludeHosts).
+			Validate(validateOptionalHostList),
+	}
+
+	if err := huh.NewForm(huh.NewGroup(fields...)).WithTheme(theme).Run(); err != nil {
+		return "", "", err
+	}
+	return strings.TrimSpace(excludePorts), strings.TrimSpace(excludeHosts), nil
+}
+
+// isCIDR reports whether target is a network range rather than one host.
+func isCIDR(target string) bool {
+	_, _, err := net.ParseCIDR(target)
+	return err == nil
+}
+
+// splitHostList turns a validated comma-separated host list into its
+// entries.
+func splitHostList(s string) []string {
+	if s == "" {
+		return nil
+	}
+	return strings.Split(s, ",")
+}
+
+// validateOptionalHostList accepts an empty string or a comma-separated
+// list of IP addresses and CIDR ranges.
+func validateOptionalHostList(s string) error {
+	s = strings.TrimSpace(s)
+	if s == "" {
+		return nil
+	}
+	for _, h := range strings.Split(s, ",") {
+		if net.ParseIP(h) == nil && !isCIDR(h) {
+			return fmt.Errorf("invalid host %q (use IPs or CIDR ranges, comma-separated)", h)
+		}
+	}
+	return nil
+}
+
+// applyPortExclusions adds --exclude-ports to every nmap command so
+// carved-out ports are honored whatever the port selection.
+func applyPortExclusions(cfg *RunConfig, exclude string) {
+	if exclude == "" {
+		return
+	}
+	for name, cmd := range cfg.Commands {
+		if strings.Contains(strings.ToLower(name), "nmap") {
+			cfg.Commands[name] = strings.Replace(cmd, "nmap ", "nmap --exclude-ports "+exclude+" ", 1)
+		}
+	}
+}
+
+// applyHostExclusions adds --exclude to every nmap command so carved-out
+// hosts inside a range target are never scanned.
+func applyHostExclusions(cfg *RunConfig, exclude string) {
+	if exclude == "" {
+		return
+	}
+	for name, cmd := range cfg.Commands {
+		if strings.Contains(strings.ToLower(name), "nmap") {
+			cfg.Commands[name] = strings.Replace(cmd, "nmap ", "nmap --exclude "+exclude+" ", 1)
+		}
+	}
+}
+
+// excludedTool is a tool dropped because it targets an excluded port.
+type excludedTool struct {
+	Name string
+	Port int
+}
+
+// dropExcludedPortTools removes tools whose command is pinned to a port
+// in exclude (http:// → 80, https:// → 443, openssl -connect host:port).
+// Unlike nmap these tools have no way to skip a port, so they can't run.
+func dropExcludedPortTools(cfg *RunConfig, exclude string) []excludedTool {
+	if exclude == "" {
+		return nil
+	}
+	var dropped []excludedTool
+	kept := cfg.Tools[:0]
+	for _, t := range cfg.Tools {
+		port, excluded := 0, false
+		for _, p := range fixedPorts(cfg.Commands[t.Name]) {
+			if portExcluded(exclude, p) {
+				port, excluded = p, true
+				break
+			}
+		}
+		if excluded {
+			dropped = append(dropped, excludedTool{t.Name, port})
+			delete(cfg.Commands, t.Name)
+			continue
+		}
+		kept = append(kept, t)
+	}
+	cfg.Tools = kept
+	return dropped
+}
+
+var (
+	urlPort     = regexp.MustCompile(`\b(https?)://(?:\[[^\]]*\]|[^\s/:'"]+)(?::(\d+))?`)
+	connectPort = regexp.MustCompile(`-connect\s+\S+:(\d+)`)
+)
+
+// fixedPorts returns the ports a command connects to on its own, from
+// URLs and openssl's -connect host:port.
+func fixedPorts(cmd string) []int {
+	var ports []int
+	for _, m := range urlPort.FindAllStringSubmatch(cmd, -1) {
+		switch {
+		case m[2] != "":
+			if p, err := strconv.Atoi(m[2]); err == nil {
+				ports = append(ports, p)
+			}
+		case m[1] == "https":
+			ports = append(ports, 443)
+		default:
+			ports = append(ports, 80)
+		}
+	}
+	for _, m := range connectPort.FindAllStringSubmatch(cmd, -1) {
+		if p, err := strconv.Atoi(m[1]); err == nil {
+			ports = append(ports, p)
+		}
+	}
+	return ports
+}
+
+// portExcluded reports whether port falls inside an nmap-style port spec
+// such as "80,443,8000-8100".
+func portExcluded(spec string, port int) bool {
+	for _, part := range strings.Split(spec, ",") {
+		lo, hi, isRange := strings.Cut(part, "-")
+		first, err := strconv.Atoi(lo)
+		if err != nil {
+			continue
+		}
+		last := first
+		if isRange {
+			if last, err = strconv.Atoi(hi); err != nil {
+				continue
+			}
+		}
+		if port >= first && port <= last {
+			return true
+		}
+	}
+	return false
+}
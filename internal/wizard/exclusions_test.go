@@ -0,0 +1,94 @@
+package wizard
+
+import (
+	"testing"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+)
+
+func TestApplyHostExclusions(t *testing.T) {
+	cfg := &RunConfig{Commands: map[string]string{
+		"Nmap SV Scan": "sudo nmap -sS -oA raw/nmap 10.0.0.0/24",
+		"Ping":         "ping -c 4 10.0.0.0/24",
+	}}
+
+	applyHostExclusions(cfg, "10.0.0.1,10.0.0.128/25")
+
+	want := "sudo nmap --exclude 10.0.0.1,10.0.0.128/25 -sS -oA raw/nmap 10.0.0.0/24"
+	if got := cfg.Commands["Nmap SV Scan"]; got != want {
+		t.Errorf("nmap command = %q, want %q", got, want)
+	}
+	if got := cfg.Commands["Ping"]; got != "ping -c 4 10.0.0.0/24" {
+		t.Errorf("non-nmap command modified: %q", got)
+	}
+}
+
+func TestValidateOptionalHostList(t *testing.T) {
+	for _, s := range []string{"", "10.0.0.1", "10.0.0.1,10.0.0.128/25", "2001:db8::1"} {
+		if err := validateOptionalHostList(s); err != nil {
+			t.Errorf("validateOptionalHostList(%q) = %v, want nil", s, err)
+		}
+	}
+	for _, s := range []string{"10.0.0.1, 10.0.0.2", "host.example.com", "10.0.0.1;id"} {
+		if err := validateOptionalHostList(s); err == nil {
+			t.Errorf("validateOptionalHostList(%q) = nil, want error", s)
+		}
+	}
+}
+
+func TestDropExcludedPortTools(t *testing.T) {
+	cfg := &RunConfig{
+		Tools: []config.Template{
+			{Name: "Nmap SV Scan"},
+			{Name: "Curl Headers"},
+			{Name: "Gobuster VHost Scan"},
+			{Name: "TLS SAN Harvest"},
+		},
+		Commands: map[string]string{
+			"Nmap SV Scan":        "nmap -sS 10.0.0.1",
+			"Curl Headers":        "curl -I -s -L -g http://10.0.0.1",
+			"Gobuster VHost Scan": "gobuster vhost -u http://[2001:db8::1] -w list",
+			"TLS SAN Harvest":     "echo | openssl s_client -connect 10.0.0.1:443 2>/dev/null",
+		},
+	}
+
+	dropped := dropExcludedPortTools(cfg, "22,80")
+
+	if len(dropped) != 2 || dropped[0] != (excludedTool{"Curl Headers", 80}) || dropped[1] != (excludedTool{"Gobuster VHost Scan", 80}) {
+		t.Errorf("dropped = %v, want Curl Headers and Gobuster VHost Scan on port 80", dropped)
+	}
+	if len(cfg.Tools) != 2 || cfg.Tools[0].Name != "Nmap SV Scan" || cfg.Tools[1].Name != "TLS SAN Harvest" {
+		t.Errorf("kept tools = %v", cfg.Tools)
+	}
+	if _, ok := cfg.Commands["Curl Headers"]; ok {
+		t.Error("dropped tool's command left behind")
+	}
+
+	dropped = dropExcludedPortTools(cfg, "400-500")
+	if len(dropped) != 1 || dropped[0] != (excludedTool{"TLS SAN Harvest", 443}) {
+		t.Errorf("dropped = %v, want TLS SAN Harvest on port 443", dropped)
+	}
+}
+
+func TestFixedPorts(t *testing.T) {
+	tests := map[string][]int{
+		"curl -I http://example.com":                  {80},
+		"feroxbuster -u https://example.com/app":      {443},
+		"curl http://[2001:db8::1]:8080/":             {8080},
+		"openssl s_client -connect [2001:db8::1]:443": {443},
+		"nmap -sS -p 80,443 10.0.0.1":                 nil,
+	}
+	for cmd, want := range tests {
+		got := fixedPorts(cmd)
+		if len(got) != len(want) {
+			t.Errorf("fixedPorts(%q) = %v, want %v", cmd, got, want)
+			continue
+		}
+		for i := range want {
+			if got[i] != want[i] {
+				t.Errorf("fixedPorts(%q) = %v, want %v", cmd, got, want)
+				break
+			}
+		}
+	}
+}
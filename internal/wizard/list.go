@@ -0,0 +1,102 @@
+package wizard
+
+import (
+	"fmt"
+	"slices"
+	"sort"
+	"strings"
+
+	"github.com/charmbracelet/lipgloss"
+	"github.com/neur0map/ipcrawler/internal/config"
+)
+
+// ListTools prints every available template with what it does, when it
+// runs, what it needs, and what it applies to.
+func ListTools(templates []config.Template) {
+	keyStyle := lipgloss.NewStyle().Foreground(tGrayL).Width(12)
+	valStyle := lipgloss.NewStyle().Foreground(tWhite)
+	descStyle := lipgloss.NewStyle().Foreground(tGray)
+
+	lastCat := ""
+	for _, t := range templates {
+		if t.Category != lastCat {
+			fmt.Printf("\n  %s\n", summCatStyle.Render("── "+strings.ToUpper(t.Category)+" ──"))
+			lastCat = t.Category
+		}
+
+		name := categoryColorFor(t.Category).Render(t.Name)
+		if t.Sudo {
+			name += " " + sudoStyle.Render("[SUDO]")
+		}
+		fmt.Printf("\n    %s\n", name)
+		if t.Description != "" {
+			fmt.Printf("    %s\n", descStyle.Render(t.Description))
+		}
+
+		targetType := t.TargetType
+		if targetType == "" {
+			targetType = "both"
+		}
+		rows := [][2]string{
+			{"target", targetType},
+			{"priority", fmt.Sprintf("%d", t.Priority)},
+			{"timeout", t.TimeoutDuration().String()},
+			{"binaries", strings.Join(extractBinaries(t.Command), ", ")},
+		}
+		if len(t.DependsOn) > 0 {
+			rows = append(rows, [2]string{"depends on", strings.Join(t.DependsOn, ", ")})
+		}
+		if len(t.Tags) > 0 {
+			rows = append(rows, [2]string{"tags", strings.Join(t.Tags, ", ")})
+		}
+		rows = append(rows, [2]string{"source", t.Source})
+		for _, r := range rows {
+			if r[1] == "" {
+				continue
+			}
+			fmt.Printf("      %s %s\n", keyStyle.Render(r[0]), valStyle.Render(r[1]))
+		}
+	}
+	fmt.Println()
+}
+
+// ListTags prints every tag with the tools that carry it. Typing a tag
+// into the tool picker's / filter and pressing a selects that group.
+func ListTags(templates []config.Template) {
+	tagStyle := lipgloss.NewStyle().Foreground(tOrange).Bold(true)
+	valStyle := lipgloss.NewStyle().Foreground(tWhite)
+
+	tags, tools := tagIndex(templates)
+	width := 0
+	for _, tag := range tags {
+		width = max(width, len(tag))
+	}
+
+	fmt.Println()
+	for _, tag := range tags {
+		fmt.Printf("  %s  %s\n",
+			tagStyle.Render(fmt.Sprintf("%-*s", width, tag)),
+			valStyle.Render(strings.Join(tools[tag], ", ")))
+	}
+	fmt.Println()
+}
+
+// tagIndex returns the sorted, lowercased tag names and, for each, the
+// tools carrying it in template order.
+func tagIndex(templates []config.Template) ([]string, map[string][]string) {
+	tools := make(map[string][]string)
+	for _, t := range templates {
+		for _, tag := range t.Tags {
+			tag = strings.ToLower(tag)
+			if !slices.Contains(tools[tag], t.Name) {
+				tools[tag] = append(tools[tag], t.Name)
+			}
+		}
+	}
+	tags := make([]string, 0, len(tools))
+	for tag := range tools {
+		tags = append(tags, tag)
+	}
+	sort.Strings(tags)
+	return tags, tools
+}
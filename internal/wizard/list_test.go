@@ -0,0 +1,26 @@
+package wizard
+
+import (
+	"reflect"
+	"testing"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+)
+
+func TestTagIndex(t *testing.T) {
+	templates := []config.Template{
+		{Name: "Nmap SV Scan", Tags: []string{"ports", "TCP"}},
+		{Name: "Nmap UDP Scan", Tags: []string{"ports", "udp"}},
+		{Name: "Ping", Tags: []string{"icmp", "tcp"}},
+		{Name: "Whois"},
+	}
+
+	tags, tools := tagIndex(templates)
+
+	if want := []string{"icmp", "ports", "tcp", "udp"}; !reflect.DeepEqual(tags, want) {
+		t.Errorf("tags = %v, want %v", tags, want)
+	}
+	if want := []string{"Nmap SV Scan", "Ping"}; !reflect.DeepEqual(tools["tcp"], want) {
+		t.Errorf("tools[tcp] = %v, want %v (case-insensitive, template order)", tools["tcp"], want)
+	}
+}
@@ -0,0 +1,159 @@
+package wizard
+
+import (
+	"errors"
+	"fmt"
+	"io/fs"
+	"os"
+	"path/filepath"
+	"regexp"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// EnvOutputTemplate names the environment variable that overrides the
+// scan directory layout under scans/.
+const EnvOutputTemplate = "IPCRAWLER_OUTPUT_TEMPLATE"
+
+// defaultOutputTemplate reproduces the historical scans/<target>_<HHMM>_<MM-DD> names.
+const defaultOutputTemplate = "{target}_{time}_{date}"
+
+// unsafePathChars matches characters that don't belong in a directory
+// name: IPv6 colons, CIDR slashes, and anything else exotic.
+var unsafePathChars = regexp.MustCompile(`[^a-zA-Z0-9._-]+`)
+
+// safeOutputTemplate limits a pattern to characters that are safe both as
+// a path and unquoted inside the tool commands that {raw_dir} lands in.
+var safeOutputTemplate = regexp.MustCompile(`^[A-Za-z0-9._{}/-]+$`)
+
+// outputPlaceholders are the tokens expandOutputTemplate understands.
+var outputPlaceholders = []string{"{target}", "{date}", "{time}", "{datetime}", "{seq}"}
+
+// outputTemplate returns $IPCRAWLER_OUTPUT_TEMPLATE, or the default when
+// it is unset, after checking that it is safe to use.
+func outputTemplate() (string, error) {
+	pattern := strings.TrimSpace(os.Getenv(EnvOutputTemplate))
+	if pattern == "" {
+		return defaultOutputTemplate, nil
+	}
+	if err := validateOutputTemplate(pattern); err != nil {
+		return "", fmt.Errorf("%s: %w", EnvOutputTemplate, err)
+	}
+	return pattern, nil
+}
+
+// validateOutputTemplate rejects patterns that could break out of scans/
+// or inject shell syntax into the commands.
+func validateOutputTemplate(pattern string) error {
+	if !safeOutputTemplate.MatchString(pattern) {
+		return fmt.Errorf("%q may only contain letters, digits, '.', '_', '-', '/' and placeholders", pattern)
+	}
+	rest := pattern
+	for _, p := range outputPlaceholders {
+		rest = strings.ReplaceAll(rest, p, "")
+	}
+	if strings.ContainsAny(rest, "{}") {
+		return fmt.Errorf("%q has an unknown placeholder", pattern)
+	}
+	for _, elem := range strings.Split(pattern, "/") {
+		if elem == "" || elem == "." || elem == ".." {
+			return fmt.Errorf("%q must be a relative path inside scans/", pattern)
+		}
+	}
+	return nil
+}
+
+// scanDir returns the scan directory for target under scans/ without
+// creating it; the wizard shows it in the summary. Run has already
+// rejected an invalid template, so this never fails.
+func scanDir(target string, now time.Time) string {
+	pattern, err := outputTemplate()
+	if err != nil {
+		pattern = defaultOutputTemplate
+	}
+	name, _ := expandOutputTemplate(pattern, target, now, func(name string) (bool, error) {
+		_, err := os.Stat(filepath.Join("scans", name))
+		return err == nil, nil
+	})
+	return filepath.Join("scans", name)
+}
+
+// createScanDir picks the scan directory like scanDir but claims it with
+// an exclusive mkdir, so two ipcrawler processes started in the same
+// minute can't end up sharing a directory.
+func createScanDir(target string, now time.Time) (string, error) {
+	pattern, err := outputTemplate()
+	if err != nil {
+		return "", err
+	}
+	name, err := expandOutputTemplate(pattern, target, now, func(name string) (bool, error) {
+		dir := filepath.Join("scans", name)
+		if err := os.MkdirAll(filepath.Dir(dir), 0755); err != nil {
+			return false, err
+		}
+		if err := os.Mkdir(dir, 0755); err != nil {
+			if errors.Is(err, fs.ErrExist) {
+				return true, nil
+			}
+			return false, err
+		}
+		return false, nil
+	})
+	if err != nil {
+		return "", fmt.Errorf("create scan directory: %w", err)
+	}
+	return filepath.Join("scans", name), nil
+}
+
+// expandOutputTemplate resolves {target}, {date}, {time}, {datetime}, and
+// {seq} in pattern. {seq} is the lowest counter from 1 whose name is not
+// taken yet; a pattern without {seq} that collides gets "_2", "_3", ...
+// appended instead. taken either checks or claims a name.
+func expandOutputTemplate(pattern, target string, now time.Time, taken func(string) (bool, error)) (string, error) {
+	name := strings.NewReplacer(
+		"{target}", unsafePathChars.ReplaceAllString(target, "_"),
+		"{date}", now.Format("01-02"),
+		"{time}", now.Format("1504"),
+		"{datetime}", now.Format("2006-01-02_150405"),
+	).Replace(pattern)
+
+	next := func(seq int) string {
+		if strings.Contains(name, "{seq}") {
+			return strings.ReplaceAll(name, "{seq}", strconv.Itoa(seq))
+		}
+		if seq == 1 {
+			return name
+		}
+		return fmt.Sprintf("%s_%d", name, seq)
+	}
+	for seq := 1; ; seq++ {
+		candidate := next(seq)
+		used, err := taken(candidate)
+		if err != nil {
+			return "", err
+		}
+		if !used {
+			return candidate, nil
+		}
+	}
+}
+
+// claimScanDir creates cfg's scan directory right before the scan starts.
+// If the name buildConfig previewed was taken in the meantime, the paths
+// under it already in the commands ({raw_dir}, built-in wordlists) move
+// to the new directory.
+func claimScanDir(cfg *RunConfig) error {
+	dir, err := createScanDir(cfg.Target, time.Now())
+	if err != nil {
+		return err
+	}
+	if dir != cfg.OutputDir {
+		sep := string(filepath.Separator)
+		for name, cmd := range cfg.Commands {
+			cfg.Commands[name] = strings.ReplaceAll(cmd, cfg.OutputDir+sep, dir+sep)
+		}
+		cfg.OutputDir = dir
+	}
+	return nil
+}
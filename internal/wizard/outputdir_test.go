@@ -0,0 +1,133 @@
+package wizard
+
+import (
+	"path/filepath"
+	"testing"
+	"time"
+)
+
+func TestExpandOutputTemplate(t *testing.T) {
+	now := time.Date(2026, 3, 7, 9, 5, 30, 0, time.UTC)
+	none := func(string) (bool, error) { return false, nil }
+
+	tests := []struct {
+		pattern string
+		target  string
+		want    string
+	}{
+		{defaultOutputTemplate, "10.0.0.1", "10.0.0.1_0905_03-07"},
+		{"{target}_{datetime}", "example.com", "example.com_2026-03-07_090530"},
+		{"{target}", "2001:db8::1", "2001_db8_1"},
+		{"{target}", "10.0.0.0/24", "10.0.0.0_24"},
+		{"engagement/{target}_{date}", "example.com", "engagement/example.com_03-07"},
+	}
+	for _, tt := range tests {
+		if got, _ := expandOutputTemplate(tt.pattern, tt.target, now, none); got != tt.want {
+			t.Errorf("expandOutputTemplate(%q, %q) = %q, want %q", tt.pattern, tt.target, got, tt.want)
+		}
+	}
+}
+
+func TestExpandOutputTemplateAvoidsCollisions(t *testing.T) {
+	now := time.Date(2026, 3, 7, 9, 5, 0, 0, time.UTC)
+	existing := map[string]bool{
+		"box_1":            true,
+		"box_2":            true,
+		"box_0905_03-07":   true,
+		"box_0905_03-07_2": true,
+	}
+	exists := func(name string) (bool, error) { return existing[name], nil }
+
+	if got, _ := expandOutputTemplate("box_{seq}", "box", now, exists); got != "box_3" {
+		t.Errorf("{seq} = %q, want box_3", got)
+	}
+	if got, _ := expandOutputTemplate(defaultOutputTemplate, "box", now, exists); got != "box_0905_03-07_3" {
+		t.Errorf("colliding default = %q, want box_0905_03-07_3", got)
+	}
+}
+
+func TestValidateOutputTemplate(t *testing.T) {
+	valid := []string{
+		defaultOutputTemplate,
+		"engagement/{target}_{seq}",
+		"box-{datetime}",
+	}
+	for _, p := range valid {
+		if err := validateOutputTemplate(p); err != nil {
+			t.Errorf("validateOutputTemplate(%q) = %v, want nil", p, err)
+		}
+	}
+
+	invalid := []string{
+		"../{target}",
+		"a/../../{target}",
+		"/tmp/{target}",
+		"a//{target}",
+		"{target};rm -rf ~",
+		"$(id)_{target}",
+		"{target} {date}",
+		"`id`",
+		"{target}_{user}",
+		"{target}'",
+	}
+	for _, p := range invalid {
+		if err := validateOutputTemplate(p); err == nil {
+			t.Errorf("validateOutputTemplate(%q) = nil, want error", p)
+		}
+	}
+}
+
+func TestCreateScanDirClaimsExclusively(t *testing.T) {
+	t.Chdir(t.TempDir())
+	t.Setenv(EnvOutputTemplate, "{target}")
+
+	now := time.Now()
+	first, err := createScanDir("box", now)
+	if err != nil {
+		t.Fatalf("createScanDir: %v", err)
+	}
+	second, err := createScanDir("box", now)
+	if err != nil {
+		t.Fatalf("createScanDir: %v", err)
+	}
+	if first != filepath.Join("scans", "box") || second != filepath.Join("scans", "box_2") {
+		t.Errorf("got %q and %q, want scans/box and scans/box_2", first, second)
+	}
+
+	t.Setenv(EnvOutputTemplate, "../{target}")
+	if _, err := createScanDir("box", now); err == nil {
+		t.Error("createScanDir accepted a template outside scans/")
+	}
+}
+
+func TestClaimScanDirMovesCommandPaths(t *testing.T) {
+	t.Chdir(t.TempDir())
+	t.Setenv(EnvOutputTemplate, "{target}")
+
+	preview := scanDir("box", time.Now())
+	if _, err := createScanDir("box", time.Now()); err != nil {
+		t.Fatal(err)
+	}
+
+	// Another launch took the previewed name before this one claimed it.
+	wordlist := shellQuote(dirBuiltin.path(preview))
+	cfg := &RunConfig{
+		Target:    "box",
+		OutputDir: preview,
+		Commands: map[string]string{
+			"Feroxbuster Dir Scan": "feroxbuster -w " + wordlist + " -o " + filepath.Join(preview, "raw", "feroxbuster.txt"),
+		},
+	}
+	if err := claimScanDir(cfg); err != nil {
+		t.Fatalf("claimScanDir: %v", err)
+	}
+
+	moved := filepath.Join("scans", "box_2")
+	if cfg.OutputDir != moved {
+		t.Fatalf("OutputDir = %q, want %q", cfg.OutputDir, moved)
+	}
+	want := "feroxbuster -w " + shellQuote(dirBuiltin.path(moved)) + " -o " + filepath.Join(moved, "raw", "feroxbuster.txt")
+	if got := cfg.Commands["Feroxbuster Dir Scan"]; got != want {
+		t.Errorf("command = %q, want %q", got, want)
+	}
+}
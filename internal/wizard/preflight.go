@@ -1,10 +1,12 @@
 package wizard
 
 import (
+	"fmt"
 	"os/exec"
 	"regexp"
 	"strings"
 
+	"github.com/charmbracelet/lipgloss"
 	"github.com/neur0map/ipcrawler/internal/config"
 )
 
@@ -21,6 +23,7 @@ var installHints = map[string]string{
 	"dnsrecon":   "pip install dnsrecon",
 	"feroxbuster": "brew install feroxbuster",
 	"gobuster":   "go install -v github.com/OJ/gobuster/v3@latest",
+	"openssl":    "brew install openssl",
 }
 
 // skipBinaries are shell builtins and coreutils that are always present.
@@ -112,6 +115,38 @@ func checkTools(tools []config.Template, commands map[string]string) []string {
 	return missing
 }
 
+// printMissingTools lists missing binaries with their install hints.
+func printMissingTools(missing []string) {
+	warn := lipgloss.NewStyle().Foreground(tRed).Bold(true)
+	hint := lipgloss.NewStyle().Foreground(tDim)
+	fmt.Println(warn.Render("\n  ✗ Missing tools:"))
+	for _, bin := range missing {
+		line := "    • " + bin
+		if h, ok := installHints[bin]; ok {
+			line += hint.Render("  (" + h + ")")
+		}
+		fmt.Println(line)
+	}
+	fmt.Println()
+}
+
+// Preflight runs the wizard's pre-run checks for a config that did not
+// come from the wizard, such as a resumed scan: every binary must be on
+// PATH, and sudo credentials are cached up front so the password prompt
+// doesn't end up hidden behind the tracker.
+func Preflight(tools []config.Template, commands map[string]string) error {
+	if missing := checkTools(tools, commands); len(missing) > 0 {
+		printMissingTools(missing)
+		return fmt.Errorf("missing tools: %s", strings.Join(missing, ", "))
+	}
+	if needsSudo(tools, commands) {
+		if err := cacheSudoCredentials(); err != nil {
+			return fmt.Errorf("sudo authentication failed: %w", err)
+		}
+	}
+	return nil
+}
+
 // filterMissing removes templates that depend on a missing binary, plus
 // any templates whose depends_on references a removed template.
 func filterMissing(tools []config.Template, commands map[string]string, missing []string) ([]config.Template, map[string]string) {
@@ -62,6 +62,11 @@ func TestExtractBinaries(t *testing.T) {
 			command: "echo 1.2.3.4 | hakrevdns -d | awk -v ip=1.2.3.4 '{print ip, $1}' >> raw/dns_resolved.txt && cat raw/dns_resolved.txt",
 			want:    []string{"hakrevdns"},
 		},
+		{
+			name:    "tls san command",
+			command: "echo | openssl s_client -connect 1.2.3.4:443 2>/dev/null | openssl x509 -noout -text 2>/dev/null | grep -o 'DNS:[^,]*' | cut -d: -f2 | awk -v ip=1.2.3.4 '$1 !~ /^\\*/ {print ip, $1}' >> raw/dns_resolved.txt && cat raw/dns_resolved.txt",
+			want:    []string{"openssl"},
+		},
 		{
 			name:    "empty command",
 			command: "",
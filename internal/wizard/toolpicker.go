@@ -4,10 +4,9 @@ import (
 	"fmt"
 	"strings"
 
-	"github.com/charmbracelet/x/ansi"
-
 	tea "github.com/charmbracelet/bubbletea"
 	"github.com/charmbracelet/lipgloss"
+	"github.com/charmbracelet/x/ansi"
 	"github.com/neur0map/ipcrawler/internal/config"
 )
 
@@ -195,8 +194,8 @@ func (m *toolPicker) ensureVisible() {
 }
 
 func (m toolPicker) viewportHeight() int {
-	// header(3 lines) + hint(2 lines) + help(1 line) + padding(2 lines)
-	vh := m.height - 8
+	// header(3 lines) + hint(3 lines) + help(1 line) + padding(2 lines)
+	vh := m.height - 9
 	if vh < 3 {
 		vh = 3
 	}
@@ -357,6 +356,14 @@ func (m toolPicker) View() string {
 			hint = ansi.Truncate(hint, maxHint, "…")
 		}
 		sb.WriteString(hintCmdStyle.Render(hint))
+		sb.WriteString("\n")
+
+		// Where the definition came from, so user overrides are visible
+		source := "  from " + m.tools[idx].Source
+		if maxHint > 0 {
+			source = ansi.Truncate(source, maxHint, "…")
+		}
+		sb.WriteString(helpStyle.Render(source))
 	}
 
 	return pickerBorder.Width(w - pickerBorder.GetHorizontalBorderSize()).Render(sb.String())
@@ -389,9 +396,7 @@ func (m toolPicker) buildLabel(t config.Template, maxName, maxWidth int) string
 	if descBudget < 10 {
 		descBudget = 10
 	}
-	if len(desc) > descBudget {
-		desc = desc[:descBudget-1] + "…"
-	}
+	desc = ansi.Truncate(desc, descBudget, "…")
 
 	// Pad description so right tags align even when desc is short
 	descPadded := fmt.Sprintf("%-*s", descBudget, desc)
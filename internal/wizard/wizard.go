@@ -14,18 +14,20 @@ import (
 	"github.com/charmbracelet/bubbles/help"
 	"github.com/charmbracelet/huh"
 	"github.com/charmbracelet/lipgloss"
+	"github.com/charmbracelet/x/ansi"
 	"github.com/neur0map/ipcrawler/internal/config"
 	"golang.org/x/term"
 )
 
 // RunConfig holds the validated configuration produced by the wizard.
 type RunConfig struct {
-	Target    string
-	Tools     []config.Template
-	Workers   int
-	Verbose   bool
-	OutputDir string
-	Commands  map[string]string // tool name → resolved command
+	Target       string
+	Tools        []config.Template
+	Workers      int
+	Verbose      bool
+	OutputDir    string
+	Commands     map[string]string // tool name → resolved command
+	ExcludeHosts []string          // out-of-scope IPs/CIDRs, dropped from discovered hosts
 }
 
 // --- Home Depot Orange palette ---
@@ -168,6 +170,12 @@ func tacticalTheme() *huh.Theme {
 func Run(templates []config.Template) (*RunConfig, error) {
 	theme := tacticalTheme()
 
+	// Fail before the forms rather than after the user has filled them in.
+	if _, err := outputTemplate(); err != nil {
+		fmt.Println(lipgloss.NewStyle().Foreground(tRed).Render("\n  ✗ " + err.Error() + "\n"))
+		return nil, err
+	}
+
 	var (
 		target  string
 		workers = 3
@@ -179,6 +187,7 @@ func Run(templates []config.Template) (*RunConfig, error) {
 		if err := collectSettings(theme, &target, &workers, &verbose); err != nil {
 			return nil, err
 		}
+		target = strings.TrimSpace(target)
 
 		// Step 2: Tool selection via custom picker
 		targetType := detectTargetType(target)
@@ -193,15 +202,15 @@ func Run(templates []config.Template) (*RunConfig, error) {
 		}
 
 		// Step 3: Wordlist selection (if fuzzing tools selected)
-		var dirWordlist, vhostWordlist string
-		if hasFeroxbuster(selected) {
-			dirWordlist, err = collectWordlist(theme, "Feroxbuster", dirPresets)
+		var dirWordlist, vhostWordlist wordlistChoice
+		if needsWordlist(selected, "dir") {
+			dirWordlist, err = collectWordlist(theme, "Directory Brute-force", dirPresets, dirBuiltin)
 			if err != nil {
 				return nil, err
 			}
 		}
-		if hasGobusterVHost(selected) {
-			vhostWordlist, err = collectWordlist(theme, "Gobuster VHost", vhostPresets)
+		if needsWordlist(selected, "vhost") {
+			vhostWordlist, err = collectWordlist(theme, "Gobuster VHost", vhostPresets, vhostBuiltin)
 			if err != nil {
 				return nil, err
 			}
@@ -216,33 +225,66 @@ func Run(templates []config.Template) (*RunConfig, error) {
 			}
 		}
 
+		// Step 5: Scope exclusions, whatever the selection
+		excludePorts, excludeHosts, err := collectExclusions(theme)
+		if err != nil {
+			return nil, err
+		}
+
 		// Build config
 		cfg := buildConfig(target, templates, selected, workers, verbose)
 
+		// Selected tools that don't apply to this target type are dropped
+		// by buildConfig — say so instead of silently running less.
+		if dropped := incompatibleSelected(templates, selected, targetType); len(dropped) > 0 {
+			warn := lipgloss.NewStyle().Foreground(tYellow)
+			fmt.Println(warn.Render(fmt.Sprintf(
+				"\n  ! Skipping %d tool(s) not applicable to a %s target:", len(dropped), targetType)))
+			for _, t := range dropped {
+				fmt.Println(warn.Render(fmt.Sprintf("    • %s (%s only)", t.Name, t.TargetType)))
+			}
+		}
+		if len(cfg.Tools) == 0 {
+			fmt.Println(lipgloss.NewStyle().Foreground(tRed).Render(
+				"\n  ✗ None of the selected tools apply to this target — returning to wizard\n"))
+			continue
+		}
+
 		// Resolve {wordlist} placeholder per tool
 		for name, cmd := range cfg.Commands {
 			if !strings.Contains(cmd, "{wordlist}") {
 				continue
 			}
-			lower := strings.ToLower(name)
-			switch {
-			case strings.Contains(lower, "feroxbuster"):
-				cfg.Commands[name] = strings.ReplaceAll(cmd, "{wordlist}", dirWordlist)
-			case strings.Contains(lower, "gobuster"):
-				cfg.Commands[name] = strings.ReplaceAll(cmd, "{wordlist}", vhostWordlist)
+			switch wordlistKind(name) {
+			case "dir":
+				cfg.Commands[name] = strings.ReplaceAll(cmd, "{wordlist}", shellQuote(dirWordlist.path(cfg.OutputDir)))
+			case "vhost":
+				cfg.Commands[name] = strings.ReplaceAll(cmd, "{wordlist}", shellQuote(vhostWordlist.path(cfg.OutputDir)))
 			}
 		}
 
-		// Replace --top-ports 100 with custom -p spec if provided
-		if nmapPorts = strings.TrimSpace(nmapPorts); nmapPorts != "" {
-			for name, cmd := range cfg.Commands {
-				if strings.Contains(strings.ToLower(name), "nmap") {
-					cfg.Commands[name] = strings.Replace(cmd, "--top-ports 100", "-p "+nmapPorts, 1)
-				}
+		// Replace each nmap template's --top-ports with the custom spec
+		applyNmapPorts(cfg, nmapPorts)
+
+		// Exclusions apply on top of whatever port selection is in effect
+		applyPortExclusions(cfg, excludePorts)
+		applyHostExclusions(cfg, excludeHosts)
+		cfg.ExcludeHosts = splitHostList(excludeHosts)
+		if dropped := dropExcludedPortTools(cfg, excludePorts); len(dropped) > 0 {
+			warn := lipgloss.NewStyle().Foreground(tYellow)
+			fmt.Println(warn.Render(fmt.Sprintf(
+				"\n  ! Skipping %d tool(s) that target an excluded port:", len(dropped))))
+			for _, d := range dropped {
+				fmt.Println(warn.Render(fmt.Sprintf("    • %s (port %d)", d.Name, d.Port)))
 			}
 		}
+		if len(cfg.Tools) == 0 {
+			fmt.Println(lipgloss.NewStyle().Foreground(tRed).Render(
+				"\n  ✗ Every selected tool targets an excluded port — returning to wizard\n"))
+			continue
+		}
 
-		// Step 4: Summary + confirmation
+		// Step 6: Summary + confirmation
 		fmt.Println(renderSummary(cfg))
 
 		var confirmed bool
@@ -263,17 +305,7 @@ func Run(templates []config.Template) (*RunConfig, error) {
 		if confirmed {
 			// Pre-flight: check that selected tools are installed.
 			if missing := checkTools(cfg.Tools, cfg.Commands); len(missing) > 0 {
-				warn := lipgloss.NewStyle().Foreground(tRed).Bold(true)
-				hint := lipgloss.NewStyle().Foreground(tDim)
-				fmt.Println(warn.Render("\n  ✗ Missing tools:"))
-				for _, bin := range missing {
-					line := "    • " + bin
-					if h, ok := installHints[bin]; ok {
-						line += hint.Render("  (" + h + ")")
-					}
-					fmt.Println(line)
-				}
-				fmt.Println()
+				printMissingTools(missing)
 
 				var cont bool
 				prompt := huh.NewForm(
@@ -306,6 +338,19 @@ func Run(templates []config.Template) (*RunConfig, error) {
 					continue
 				}
 			}
+			if err := claimScanDir(cfg); err != nil {
+				fmt.Println(lipgloss.NewStyle().Foreground(tRed).Render("\n  ✗ " + err.Error() + "\n"))
+				return nil, err
+			}
+			for _, c := range []wordlistChoice{dirWordlist, vhostWordlist} {
+				if c.Builtin == nil {
+					continue
+				}
+				if _, err := c.Builtin.materialize(cfg.OutputDir); err != nil {
+					fmt.Println(lipgloss.NewStyle().Foreground(tRed).Render("\n  ✗ " + err.Error() + "\n"))
+					return nil, err
+				}
+			}
 			return cfg, nil
 		}
 	}
@@ -320,12 +365,7 @@ func collectSettings(theme *huh.Theme, target *string, workers *int, verbose *bo
 				Description("IP address or domain").
 				Placeholder("192.168.1.1 or example.com").
 				Value(target).
-				Validate(func(s string) error {
-					if strings.TrimSpace(s) == "" {
-						return fmt.Errorf("target cannot be empty")
-					}
-					return nil
-				}),
+				Validate(validateTarget),
 		),
 		huh.NewGroup(
 			huh.NewSelect[int]().
@@ -353,26 +393,18 @@ func collectSettings(theme *huh.Theme, target *string, workers *int, verbose *bo
 	return form.Run()
 }
 
-// collectNmapPorts runs the port override form for nmap.
+// collectNmapPorts runs the port override form for nmap. Returns the
+// custom port spec, or empty to keep each template's default.
 func collectNmapPorts(theme *huh.Theme) (string, error) {
 	var nmapPorts string
 	form := huh.NewForm(
 		huh.NewGroup(
 			huh.NewInput().
 				Title("Nmap Ports").
-				Description("Custom ports/range — leave empty for top 100").
+				Description("Custom ports/range for the TCP and UDP scans — leave empty for top 100 TCP / top 20 UDP").
 				Placeholder("e.g. 80,443  or  1-1024  or  22,80,443-500").
 				Value(&nmapPorts).
-				Validate(func(s string) error {
-					s = strings.TrimSpace(s)
-					if s == "" {
-						return nil
-					}
-					if !validPortSpec.MatchString(s) {
-						return fmt.Errorf("invalid port spec (use digits, commas, dashes)")
-					}
-					return nil
-				}),
+				Validate(validateOptionalPortSpec),
 		),
 	).WithTheme(theme)
 
@@ -382,6 +414,39 @@ func collectNmapPorts(theme *huh.Theme) (string, error) {
 	return strings.TrimSpace(nmapPorts), nil
 }
 
+// topPorts matches the default port selection in the nmap templates.
+var topPorts = regexp.MustCompile(`--top-ports \d+`)
+
+// applyNmapPorts swaps --top-ports for -p spec in every nmap command.
+// UDP scans get the spec as U:spec so nmap reads the ports as UDP.
+func applyNmapPorts(cfg *RunConfig, spec string) {
+	if spec == "" {
+		return
+	}
+	for name, cmd := range cfg.Commands {
+		if !strings.Contains(strings.ToLower(name), "nmap") {
+			continue
+		}
+		ports := "-p " + spec
+		if strings.Contains(cmd, "-sU") {
+			ports = "-p U:" + spec
+		}
+		cfg.Commands[name] = topPorts.ReplaceAllLiteralString(cmd, ports)
+	}
+}
+
+// validateOptionalPortSpec accepts an empty string or a valid nmap port spec.
+func validateOptionalPortSpec(s string) error {
+	s = strings.TrimSpace(s)
+	if s == "" {
+		return nil
+	}
+	if !validPortSpec.MatchString(s) {
+		return fmt.Errorf("invalid port spec (use digits, commas, dashes)")
+	}
+	return nil
+}
+
 // filterByTargetType returns templates compatible with the detected target type.
 func filterByTargetType(templates []config.Template, targetType string) []config.Template {
 	var out []config.Template
@@ -403,10 +468,7 @@ func termWidth() int {
 }
 
 func buildConfig(target string, templates []config.Template, selected []string, workers int, verbose bool) *RunConfig {
-	now := time.Now()
-	timeStamp := now.Format("1504")
-	dateStamp := now.Format("01-02")
-	outputDir := filepath.Join("scans", fmt.Sprintf("%s_%s_%s", target, timeStamp, dateStamp))
+	outputDir := scanDir(target, time.Now())
 
 	targetType := detectTargetType(target)
 	tools := filterTemplates(templates, selected, targetType)
@@ -417,6 +479,10 @@ func buildConfig(target string, templates []config.Template, selected []string,
 	for _, t := range tools {
 		cmd := t.ResolveCommand(target)
 		cmd = strings.ReplaceAll(cmd, "{raw_dir}", rawDir)
+		// nmap only scans IPv6 addresses when told to
+		if isIPv6(target) && strings.Contains(strings.ToLower(t.Name), "nmap") {
+			cmd = strings.Replace(cmd, "nmap ", "nmap -6 ", 1)
+		}
 		if t.Sudo {
 			cmd = "sudo " + cmd
 		}
@@ -473,10 +539,7 @@ func renderSummary(cfg *RunConfig) string {
 	for _, cat := range sortedCategories(grouped) {
 		fmt.Fprintf(&sb, "  %s\n", summCatStyle.Render("── "+strings.ToUpper(cat)+" ──"))
 		for _, entry := range grouped[cat] {
-			display := entry
-			if len(display) > maxCmd {
-				display = display[:maxCmd-1] + "…"
-			}
+			display := ansi.Truncate(entry, maxCmd, "…")
 			fmt.Fprintf(&sb, "    %s\n", cmdStyle.Render("$ "+display))
 		}
 		sb.WriteString("\n")
@@ -516,6 +579,52 @@ func filterTemplates(templates []config.Template, selected []string, targetType
 	return out
 }
 
+// validHostname matches RFC 1123 hostnames (underscores tolerated for
+// real-world DNS). Anything else is rejected before it reaches `sh -c`.
+var validHostname = regexp.MustCompile(`^[a-zA-Z0-9_]([a-zA-Z0-9_-]*[a-zA-Z0-9])?(\.[a-zA-Z0-9_]([a-zA-Z0-9_-]*[a-zA-Z0-9])?)*\.?$`)
+
+// validateTarget accepts an IP, CIDR, or hostname. The target is
+// interpolated into shell commands, so shell metacharacters, spaces,
+// and URLs are refused outright.
+func validateTarget(s string) error {
+	t := strings.TrimSpace(s)
+	if t == "" {
+		return fmt.Errorf("target cannot be empty")
+	}
+	if net.ParseIP(t) != nil {
+		return nil
+	}
+	if _, _, err := net.ParseCIDR(t); err == nil {
+		return nil
+	}
+	if len(t) > 253 || !validHostname.MatchString(t) {
+		return fmt.Errorf("invalid target (use an IP, CIDR, or hostname)")
+	}
+	return nil
+}
+
+// shellQuote wraps s in single quotes for safe interpolation into a
+// `sh -c` command string.
+func shellQuote(s string) string {
+	return "'" + strings.ReplaceAll(s, "'", `'\''`) + "'"
+}
+
+// incompatibleSelected returns selected templates whose target_type
+// doesn't match the detected target.
+func incompatibleSelected(templates []config.Template, selected []string, targetType string) []config.Template {
+	set := make(map[string]bool, len(selected))
+	for _, s := range selected {
+		set[s] = true
+	}
+	var out []config.Template
+	for _, t := range templates {
+		if set[t.Name] && !isCompatible(t.TargetType, targetType) {
+			out = append(out, t)
+		}
+	}
+	return out
+}
+
 // detectTargetType returns "ip", "domain", or "both" based on the input string.
 func detectTargetType(target string) string {
 	t := strings.TrimSpace(target)
@@ -531,6 +640,18 @@ func detectTargetType(target string) string {
 	return "domain"
 }
 
+// isIPv6 reports whether target is an IPv6 address or CIDR.
+func isIPv6(target string) bool {
+	ip := net.ParseIP(target)
+	if ip == nil {
+		var err error
+		if ip, _, err = net.ParseCIDR(target); err != nil {
+			return false
+		}
+	}
+	return ip.To4() == nil
+}
+
 // hasNmap returns true if any selected tool name contains "nmap" (case-insensitive).
 func hasNmap(selected []string) bool {
 	for _, s := range selected {
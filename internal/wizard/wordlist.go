@@ -1,6 +1,7 @@
 package wizard
 
 import (
+	_ "embed"
 	"fmt"
 	"os"
 	"path/filepath"
@@ -16,7 +17,7 @@ type wordlistPreset struct {
 	RelPath  string // path relative to SecLists root
 }
 
-// Directory busting presets (feroxbuster)
+// Directory busting presets (feroxbuster, gobuster dir)
 var dirPresets = []wordlistPreset{
 	{"raft-medium-directories.txt (~30k)", "Discovery/Web-Content/raft-medium-directories.txt"},
 	{"directory-list-2.3-medium.txt (~220k)", "Discovery/Web-Content/directory-list-2.3-medium.txt"},
@@ -30,16 +31,107 @@ var vhostPresets = []wordlistPreset{
 	{"namelist.txt (~1.9k)", "Discovery/DNS/namelist.txt"},
 }
 
+// Minimal built-in wordlists so fuzzing tools work with zero setup.
+var (
+	//go:embed wordlists/directories.txt
+	builtinDirWords string
+
+	//go:embed wordlists/vhosts.txt
+	builtinVHostWords string
+)
+
+// builtinWordlist is an embedded wordlist written to disk on demand,
+// since the fuzzing tools only accept file paths. It goes into the scan's
+// raw/ directory so it is kept with the scan (and still there on resume)
+// instead of piling up in the temp dir.
+type builtinWordlist struct {
+	FileName string
+	Content  string
+}
+
+var (
+	dirBuiltin   = builtinWordlist{"wordlist_directories.txt", builtinDirWords}
+	vhostBuiltin = builtinWordlist{"wordlist_vhosts.txt", builtinVHostWords}
+)
+
+// label returns the select option text including the entry count.
+func (b builtinWordlist) label() string {
+	return fmt.Sprintf("Built-in (~%d, no SecLists needed)", strings.Count(b.Content, "\n"))
+}
+
+// path returns where the wordlist lives for a scan in outputDir.
+func (b builtinWordlist) path(outputDir string) string {
+	return filepath.Join(outputDir, "raw", b.FileName)
+}
+
+// materialize writes the wordlist into outputDir's raw/ directory and
+// returns its path. The file is created with O_EXCL, so an existing file
+// or symlink at that path is never followed or overwritten.
+func (b builtinWordlist) materialize(outputDir string) (string, error) {
+	path := b.path(outputDir)
+	if err := os.MkdirAll(filepath.Dir(path), 0755); err != nil {
+		return "", fmt.Errorf("write built-in wordlist: %w", err)
+	}
+	f, err := os.OpenFile(path, os.O_WRONLY|os.O_CREATE|os.O_EXCL, 0644)
+	if err != nil {
+		return "", fmt.Errorf("write built-in wordlist: %w", err)
+	}
+	if _, err := f.WriteString(b.Content); err != nil {
+		_ = f.Close()
+		_ = os.Remove(path)
+		return "", fmt.Errorf("write built-in wordlist: %w", err)
+	}
+	if err := f.Close(); err != nil {
+		_ = os.Remove(path)
+		return "", fmt.Errorf("write built-in wordlist: %w", err)
+	}
+	return path, nil
+}
+
+// wordlistChoice is a picked wordlist: a file already on disk, or a
+// built-in list that is written out once the scan directory exists.
+type wordlistChoice struct {
+	Path    string
+	Builtin *builtinWordlist
+}
+
+// path returns where the chosen wordlist is (or will be) for a scan in
+// outputDir.
+func (c wordlistChoice) path(outputDir string) string {
+	if c.Builtin != nil {
+		return c.Builtin.path(outputDir)
+	}
+	return c.Path
+}
+
 // seclistsPaths are checked in order to find the SecLists installation.
 var seclistsPaths = []string{
 	"/usr/share/seclists",
 	"/usr/share/SecLists",
 	"/opt/homebrew/share/seclists",
+	"/usr/local/share/seclists",
+	"/opt/SecLists",
+}
+
+// seclistsInstallHint is shown whenever SecLists can't be found.
+const seclistsInstallHint = "apt install seclists  ·  brew install seclists  ·  git clone https://github.com/danielmiessler/SecLists ~/.local/share/seclists"
+
+// seclistsCandidates returns the system paths plus per-user locations.
+func seclistsCandidates() []string {
+	paths := append([]string{}, seclistsPaths...)
+	if home, err := os.UserHomeDir(); err == nil {
+		paths = append(paths,
+			filepath.Join(home, ".local", "share", "seclists"),
+			filepath.Join(home, "SecLists"))
+	}
+	return paths
 }
 
-// findSecLists returns the SecLists base directory, or empty string if not found.
+// findSecLists returns the SecLists base directory, or empty string if not
+// found. Only directories that actually exist are returned — callers never
+// build wordlist paths under a guessed root.
 func findSecLists() string {
-	for _, p := range seclistsPaths {
+	for _, p := range seclistsCandidates() {
 		if info, err := os.Stat(p); err == nil && info.IsDir() {
 			return p
 		}
@@ -47,20 +139,24 @@ func findSecLists() string {
 	return ""
 }
 
-// hasFeroxbuster returns true if any selected tool name contains "feroxbuster".
-func hasFeroxbuster(selected []string) bool {
-	for _, s := range selected {
-		if strings.Contains(strings.ToLower(s), "feroxbuster") {
-			return true
-		}
+// wordlistKind returns which wordlist a tool's {wordlist} placeholder
+// takes: "vhost" for virtual host discovery, "dir" for directory
+// brute-forcers (feroxbuster, gobuster dir), or "" for neither.
+func wordlistKind(toolName string) string {
+	lower := strings.ToLower(toolName)
+	switch {
+	case strings.Contains(lower, "vhost"):
+		return "vhost"
+	case strings.Contains(lower, "feroxbuster"), strings.Contains(lower, "gobuster"):
+		return "dir"
 	}
-	return false
+	return ""
 }
 
-// hasGobusterVHost returns true if any selected tool name contains "gobuster".
-func hasGobusterVHost(selected []string) bool {
+// needsWordlist returns true if any selected tool takes a wordlist of kind.
+func needsWordlist(selected []string, kind string) bool {
 	for _, s := range selected {
-		if strings.Contains(strings.ToLower(s), "gobuster") {
+		if wordlistKind(s) == kind {
 			return true
 		}
 	}
@@ -68,26 +164,31 @@ func hasGobusterVHost(selected []string) bool {
 }
 
 // collectWordlist shows a wordlist picker for a fuzzing tool.
-// Returns the validated absolute path to the wordlist file.
-func collectWordlist(theme *huh.Theme, toolLabel string, presets []wordlistPreset) (string, error) {
+// Returns the validated path to the wordlist file, or the built-in list
+// to write out later. The embedded built-in list is always offered, and
+// is the default when SecLists is missing.
+func collectWordlist(theme *huh.Theme, toolLabel string, presets []wordlistPreset, builtin builtinWordlist) (wordlistChoice, error) {
 	secBase := findSecLists()
 
-	// If SecLists not found, go straight to custom path input
-	if secBase == "" {
-		return collectCustomWordlist(theme, toolLabel,
-			"SecLists not found — enter full wordlist path")
-	}
-
 	// Build select options from presets
-	const customValue = "__custom__"
-	options := make([]huh.Option[string], 0, len(presets)+1)
-	for i, p := range presets {
-		fullPath := filepath.Join(secBase, p.RelPath)
-		label := p.Label
-		if i == 0 {
-			label += " (Recommended)"
+	const (
+		customValue  = "__custom__"
+		builtinValue = "__builtin__"
+	)
+	options := make([]huh.Option[string], 0, len(presets)+2)
+	if secBase == "" {
+		warnSecListsMissing()
+		options = append(options, huh.NewOption(builtin.label()+" (Recommended)", builtinValue))
+	} else {
+		for i, p := range presets {
+			fullPath := filepath.Join(secBase, p.RelPath)
+			label := p.Label
+			if i == 0 {
+				label += " (Recommended)"
+			}
+			options = append(options, huh.NewOption(label, fullPath))
 		}
-		options = append(options, huh.NewOption(label, fullPath))
+		options = append(options, huh.NewOption(builtin.label(), builtinValue))
 	}
 	options = append(options, huh.NewOption("Custom path", customValue))
 
@@ -102,21 +203,39 @@ func collectWordlist(theme *huh.Theme, toolLabel string, presets []wordlistPrese
 	).WithTheme(theme)
 
 	if err := form.Run(); err != nil {
-		return "", err
-	}
-
-	if selected == customValue {
-		return collectCustomWordlist(theme, toolLabel, "Enter wordlist path")
+		return wordlistChoice{}, err
 	}
 
-	// Validate preset path exists
-	if _, err := os.Stat(selected); err != nil {
-		// Preset file missing — fall back to custom input with error
-		return collectCustomWordlist(theme, toolLabel,
-			fmt.Sprintf("File not found: %s — enter path", selected))
+	var (
+		path string
+		err  error
+	)
+	switch selected {
+	case customValue:
+		path, err = collectCustomWordlist(theme, toolLabel, "Enter wordlist path")
+	case builtinValue:
+		return wordlistChoice{Builtin: &builtin}, nil
+	default:
+		path = selected
+		// Validate preset path exists
+		if _, statErr := os.Stat(selected); statErr != nil {
+			// Preset file missing — fall back to custom input with error
+			path, err = collectCustomWordlist(theme, toolLabel,
+				fmt.Sprintf("File not found: %s — enter path", selected))
+		}
 	}
+	return wordlistChoice{Path: path}, err
+}
 
-	return selected, nil
+// warnSecListsMissing tells the user why no presets are offered and how
+// to get them.
+func warnSecListsMissing() {
+	warn := lipgloss.NewStyle().Foreground(tYellow).Bold(true)
+	hint := lipgloss.NewStyle().Foreground(tDim)
+	fmt.Println(warn.Render("\n  ! SecLists not found — falling back to the built-in wordlist"))
+	fmt.Println(hint.Render("    checked: " + strings.Join(seclistsCandidates(), ", ")))
+	fmt.Println(hint.Render("    install: " + seclistsInstallHint))
+	fmt.Println()
 }
 
 // collectCustomWordlist prompts for a manual wordlist path with validation.
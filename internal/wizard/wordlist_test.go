@@ -0,0 +1,88 @@
+package wizard
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+)
+
+func TestBuiltinWordlists(t *testing.T) {
+	for _, b := range []builtinWordlist{dirBuiltin, vhostBuiltin} {
+		t.Run(b.FileName, func(t *testing.T) {
+			seen := map[string]bool{}
+			for i, line := range strings.Split(strings.TrimRight(b.Content, "\n"), "\n") {
+				if strings.TrimSpace(line) == "" {
+					t.Errorf("line %d is blank", i+1)
+				}
+				if seen[line] {
+					t.Errorf("duplicate entry %q", line)
+				}
+				seen[line] = true
+			}
+			if len(seen) < 100 {
+				t.Errorf("only %d entries, expected a useful list", len(seen))
+			}
+		})
+	}
+}
+
+func TestBuiltinMaterialize(t *testing.T) {
+	dir := t.TempDir()
+
+	path, err := vhostBuiltin.materialize(dir)
+	if err != nil {
+		t.Fatalf("materialize: %v", err)
+	}
+	if want := filepath.Join(dir, "raw", vhostBuiltin.FileName); path != want {
+		t.Errorf("path = %q, want %q", path, want)
+	}
+	got, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(got) != vhostBuiltin.Content {
+		t.Error("materialized wordlist does not match embedded content")
+	}
+}
+
+func TestBuiltinMaterializeIgnoresPlantedSymlink(t *testing.T) {
+	dir := t.TempDir()
+	if err := os.MkdirAll(filepath.Join(dir, "raw"), 0755); err != nil {
+		t.Fatal(err)
+	}
+
+	// A symlink already at the target path must not redirect the write.
+	victim := filepath.Join(t.TempDir(), "victim")
+	if err := os.WriteFile(victim, []byte("keep"), 0644); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.Symlink(victim, vhostBuiltin.path(dir)); err != nil {
+		t.Fatal(err)
+	}
+
+	if _, err := vhostBuiltin.materialize(dir); err == nil {
+		t.Error("materialize wrote through an existing symlink")
+	}
+	got, err := os.ReadFile(victim)
+	if err != nil {
+		t.Fatal(err)
+	}
+	if string(got) != "keep" {
+		t.Error("symlink target was overwritten")
+	}
+}
+
+func TestWordlistKind(t *testing.T) {
+	tests := map[string]string{
+		"Feroxbuster Dir Scan": "dir",
+		"Gobuster Dir Scan":    "dir",
+		"Gobuster VHost Scan":  "vhost",
+		"Nmap SV Scan":         "",
+	}
+	for name, want := range tests {
+		if got := wordlistKind(name); got != want {
+			t.Errorf("wordlistKind(%q) = %q, want %q", name, got, want)
+		}
+	}
+}
@@ -1,6 +1,7 @@
 package main
 
 import (
+	"bufio"
 	"context"
 	"embed"
 	"fmt"
@@ -15,10 +16,13 @@ import (
 	"github.com/charmbracelet/glamour"
 	"github.com/charmbracelet/lipgloss"
 	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+	"github.com/neur0map/ipcrawler/internal/notify"
 	"github.com/neur0map/ipcrawler/internal/report"
 	"github.com/neur0map/ipcrawler/internal/runner"
 	"github.com/neur0map/ipcrawler/internal/tracker"
 	"github.com/neur0map/ipcrawler/internal/wizard"
+	"golang.org/x/term"
 )
 
 //go:embed templates/*/*.yaml
@@ -33,16 +37,34 @@ var (
 			Bold(true).
 			Foreground(orange)
 
+	dimStyle = lipgloss.NewStyle().
+			Foreground(lipgloss.Color("#6C6C6C"))
+
 )
 
 func main() {
 	fmt.Println(bannerStyle.Render("\n  ipcrawler v0.1.0 — Security Tool Orchestrator\n"))
 
-	templates, err := config.ParseTemplates(templateFS)
+	templates, overrides, err := config.LoadTemplates(templateFS, config.TemplateDirs())
 	if err != nil {
 		fmt.Fprintf(os.Stderr, "Error loading templates: %v\n", err)
 		os.Exit(1)
 	}
+	for _, o := range overrides {
+		fmt.Println(dimStyle.Render(fmt.Sprintf("  ↻ %s overridden by %s", o.Name, o.Path)))
+	}
+
+	if len(os.Args) > 1 {
+		os.Exit(runSubcommand(os.Args[1:], templates))
+	}
+
+	// The wizard is a set of interactive forms; without a TTY it would
+	// either hang or fail cryptically, so bail out early and clearly.
+	if !term.IsTerminal(int(os.Stdin.Fd())) {
+		fmt.Fprintln(os.Stderr, "Error: the ipcrawler wizard needs an interactive terminal (stdin is not a TTY).")
+		fmt.Fprintln(os.Stderr, "Non-interactive commands: ipcrawler list, ipcrawler tags, ipcrawler doctor, ipcrawler resume <scan-dir>")
+		os.Exit(2)
+	}
 
 	runCfg, err := wizard.Run(templates)
 	if err != nil {
@@ -50,37 +72,38 @@ func main() {
 		os.Exit(0)
 	}
 
-	for _, d := range []string{"raw", "errors", "logs"} {
-		if err := os.MkdirAll(filepath.Join(runCfg.OutputDir, d), 0755); err != nil {
-			fmt.Fprintf(os.Stderr, "Error creating directories: %v\n", err)
-			os.Exit(1)
-		}
+	if err := fsutil.MakeScanDirs(runCfg.OutputDir); err != nil {
+		fmt.Fprintf(os.Stderr, "Error creating directories: %v\n", err)
+		os.Exit(1)
 	}
 
-	// --- Execute ---
-
 	// Sort tools by priority (lowest first) so both runner and tracker
-	// see the same execution-wave order.
-	sort.SliceStable(runCfg.Tools, func(i, j int) bool {
-		return runCfg.Tools[i].Priority < runCfg.Tools[j].Priority
-	})
+	// see the same execution-wave order. Ties break on category then name
+	// so report sections come out in the same order on every run.
+	sortTools(runCfg.Tools)
+
+	if err := runner.SaveRunConfig(runCfg); err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: could not save run.json, this scan cannot be resumed: %v\n", err)
+	}
 
+	runScan(runCfg, runner.New(runCfg))
+}
+
+// runScan executes r with the live tracker, then writes the report and
+// the other scan artifacts into runCfg.OutputDir.
+func runScan(runCfg *wizard.RunConfig, r *runner.Runner) {
+	// --- Execute ---
+
+	start := time.Now()
 	ctx, cancel := context.WithCancel(context.Background())
 	defer cancel()
 
-	r := runner.New(runCfg)
-
 	done := make(chan struct{})
 	go func() {
 		r.Execute(ctx)
 		close(done)
 	}()
 
-	toolNames := make([]string, len(runCfg.Tools))
-	for i, t := range runCfg.Tools {
-		toolNames[i] = t.Name
-	}
-
 	if runCfg.Verbose {
 		sigCh := make(chan os.Signal, 1)
 		signal.Notify(sigCh, os.Interrupt)
@@ -90,7 +113,7 @@ func main() {
 		}()
 		tracker.RunVerbose(r.Updates)
 	} else {
-		m := tracker.NewModel(r.Updates, toolNames, cancel)
+		m := tracker.NewModel(r.Updates, r.JobNames(), cancel)
 		p := tea.NewProgram(m)
 		finalModel, _ := p.Run()
 
@@ -102,15 +125,40 @@ func main() {
 		}
 	}
 
+	// The context is only cancelled early by Ctrl+C
+	interrupted := ctx.Err() != nil
 	cancel()
 	<-done
 
 	// --- Report ---
 
 	reportData := buildReportData(runCfg, r.Results())
+	reportData.Interrupted = interrupted
 	if err := report.Compile(runCfg.OutputDir, reportData); err != nil {
 		fmt.Fprintf(os.Stderr, "Error compiling report: %v\n", err)
 	}
+	if err := report.WriteJSON(runCfg.OutputDir, reportData); err != nil {
+		fmt.Fprintf(os.Stderr, "Error writing results.json: %v\n", err)
+	}
+
+	// Always emit discovered hosts in /etc/hosts format so they can be
+	// pasted manually, whether or not Hosts Updater ran with sudo.
+	// Out-of-scope hosts are dropped first; Hosts Updater may not have run.
+	if dropped, err := report.FilterResolvedHosts(runCfg.OutputDir, runCfg.ExcludeHosts); err != nil {
+		fmt.Fprintf(os.Stderr, "Error filtering excluded hosts: %v\n", err)
+	} else if len(dropped) > 0 {
+		fmt.Printf("Dropped %d out-of-scope host entries from dns_resolved.txt\n", len(dropped))
+	}
+	var hostsPath string
+	if n, err := report.WriteHostsAdditions(runCfg.OutputDir); err != nil {
+		fmt.Fprintf(os.Stderr, "Error writing hosts additions: %v\n", err)
+	} else if n > 0 {
+		hostsPath = filepath.Join(runCfg.OutputDir, "hosts_additions.txt")
+	}
+
+	if err := report.WriteManifest(runCfg.OutputDir, runCfg.Target, runCfg.Commands); err != nil {
+		fmt.Fprintf(os.Stderr, "Error writing manifest: %v\n", err)
+	}
 
 	// Render the report to terminal via glamour
 	reportPath := filepath.Join(runCfg.OutputDir, "report.md")
@@ -131,7 +179,226 @@ func main() {
 		Padding(0, 1).
 		Border(lipgloss.RoundedBorder()).
 		BorderForeground(orange)
-	fmt.Println(savePrompt.Render("Report saved to " + reportPath))
+	saved := "Report saved to " + reportPath
+	if hostsPath != "" {
+		saved += "\nHosts entries saved to " + hostsPath
+	}
+	if interrupted {
+		saved += "\nPartial scan — finish it with: ipcrawler resume " + runCfg.OutputDir
+	}
+	fmt.Println(savePrompt.Render(saved))
+
+	// Best-effort completion ping — a broken webhook never fails the scan
+	if webhook := notify.WebhookURL(); webhook != "" {
+		statuses := make(map[string]int)
+		for _, res := range reportData.Results {
+			statuses[res.Status]++
+		}
+		err := notify.Send(webhook, notify.Summary{
+			Target:      runCfg.Target,
+			OutputDir:   runCfg.OutputDir,
+			Duration:    time.Since(start),
+			Interrupted: interrupted,
+			Statuses:    statuses,
+		})
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Warning: completion webhook: %v\n", err)
+		}
+	}
+}
+
+// sortTools orders tools by priority, then category, then name.
+func sortTools(tools []config.Template) {
+	sort.SliceStable(tools, func(i, j int) bool {
+		a, b := tools[i], tools[j]
+		if a.Priority != b.Priority {
+			return a.Priority < b.Priority
+		}
+		if a.Category != b.Category {
+			return a.Category < b.Category
+		}
+		return a.Name < b.Name
+	})
+}
+
+// runSubcommand handles the non-interactive commands and returns the
+// process exit code.
+func runSubcommand(args []string, templates []config.Template) int {
+	switch args[0] {
+	case "doctor":
+		if !wizard.Doctor(templates) {
+			return 1
+		}
+		return 0
+	case "list":
+		wizard.ListTools(templates)
+		return 0
+	case "tags":
+		wizard.ListTags(templates)
+		return 0
+	case "hosts":
+		if len(args) < 2 || args[1] != "restore" {
+			fmt.Fprintln(os.Stderr, "Usage: ipcrawler hosts restore [--latest | --backup <path>]")
+			return 2
+		}
+		return restoreHosts(args[2:])
+	case "resume":
+		if len(args) < 2 {
+			fmt.Fprintln(os.Stderr, "Usage: ipcrawler resume <scan-dir>")
+			return 2
+		}
+		return resume(args[1], templates)
+	default:
+		fmt.Fprintf(os.Stderr, "Unknown command %q\n\n", args[0])
+		fmt.Fprintln(os.Stderr, "Usage:")
+		fmt.Fprintln(os.Stderr, "  ipcrawler           launch the interactive wizard")
+		fmt.Fprintln(os.Stderr, "  ipcrawler list      describe every available tool template")
+		fmt.Fprintln(os.Stderr, "  ipcrawler tags      show which tools carry each tag")
+		fmt.Fprintln(os.Stderr, "  ipcrawler doctor    check installed tools, wordlists, and permissions")
+		fmt.Fprintln(os.Stderr, "  ipcrawler hosts restore [--latest | --backup <path>]")
+		fmt.Fprintln(os.Stderr, "                      remove ipcrawler's entries from /etc/hosts, or put a backup back")
+		fmt.Fprintln(os.Stderr, "  ipcrawler resume <scan-dir>")
+		fmt.Fprintln(os.Stderr, "                      re-run the tools an interrupted scan did not finish")
+		return 2
+	}
+}
+
+// restoreHosts undoes Hosts Updater. By default it strips the ipcrawler
+// block from /etc/hosts; with --latest or --backup <path> it puts a saved
+// backup back instead. Either way the current file is backed up first and
+// nothing is written until the user confirms.
+func restoreHosts(args []string) int {
+	const hostsFile = "/etc/hosts"
+
+	var backup string
+	latest := false
+	switch {
+	case len(args) == 0:
+	case len(args) == 1 && args[0] == "--latest":
+		latest = true
+	case len(args) == 2 && args[0] == "--backup":
+		backup = args[1]
+	default:
+		fmt.Fprintln(os.Stderr, "Usage: ipcrawler hosts restore [--latest | --backup <path>]")
+		return 2
+	}
+
+	if os.Geteuid() != 0 {
+		fmt.Fprintln(os.Stderr, "Error: restoring /etc/hosts needs root — run: sudo ipcrawler hosts restore")
+		return 1
+	}
+
+	backups, err := report.ListHostsBackups(hostsFile)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Warning: could not list backups: %v\n", err)
+	}
+	if len(backups) > 0 {
+		fmt.Println("Backups of " + hostsFile + " (newest first):")
+		for _, b := range backups {
+			fmt.Printf("  %s  %s\n", b.Time.Format("2006-01-02 15:04:05"), b.Path)
+		}
+		fmt.Println()
+	}
+	if latest {
+		if len(backups) == 0 {
+			fmt.Fprintln(os.Stderr, "Error: no backups of "+hostsFile+" found")
+			return 1
+		}
+		backup = backups[0].Path
+	}
+
+	if backup != "" {
+		if err := report.CheckHostsBackup(backup); err != nil {
+			fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+			return 1
+		}
+		if !confirm(fmt.Sprintf("Replace %s with %s?", hostsFile, backup)) {
+			fmt.Println("Aborted.")
+			return 0
+		}
+		safety, err := report.RestoreHostsBackup(hostsFile, backup)
+		if err != nil {
+			fmt.Fprintf(os.Stderr, "Error restoring %s: %v\n", hostsFile, err)
+			return 1
+		}
+		fmt.Printf("Restored %s from %s (previous version saved to %s)\n", hostsFile, backup, safety)
+		return 0
+	}
+
+	n, err := report.HostsEntries(hostsFile)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error reading %s: %v\n", hostsFile, err)
+		return 1
+	}
+	if n == 0 {
+		fmt.Println("No ipcrawler entries in " + hostsFile + " — nothing to do.")
+		return 0
+	}
+	if !confirm(fmt.Sprintf("Remove %d ipcrawler lines from %s?", n, hostsFile)) {
+		fmt.Println("Aborted.")
+		return 0
+	}
+	n, saved, err := report.RestoreHosts(hostsFile)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error restoring %s: %v\n", hostsFile, err)
+		return 1
+	}
+	fmt.Printf("Removed %d lines from %s (previous version saved to %s)\n", n, hostsFile, saved)
+	return 0
+}
+
+// confirm asks a yes/no question on the terminal; anything but y/yes is no.
+func confirm(question string) bool {
+	fmt.Printf("%s [y/N] ", question)
+	line, _ := bufio.NewReader(os.Stdin).ReadString('\n')
+	switch strings.ToLower(strings.TrimSpace(line)) {
+	case "y", "yes":
+		return true
+	}
+	return false
+}
+
+// resume reloads an interrupted scan from its output directory and runs
+// every tool that has not yet succeeded.
+func resume(dir string, templates []config.Template) int {
+	runCfg, prev, missing, err := runner.LoadResume(dir, templates)
+	if err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		return 1
+	}
+	for _, name := range missing {
+		fmt.Fprintf(os.Stderr, "Warning: %q is no longer in the templates — skipping it\n", name)
+	}
+
+	sortTools(runCfg.Tools)
+	r := runner.NewResumed(runCfg, prev)
+	fmt.Println(dimStyle.Render(fmt.Sprintf("  Resuming %s — %d of %d tools left",
+		dir, len(r.JobNames()), len(runCfg.Tools))))
+
+	// Same checks the wizard runs before a fresh scan: the tracker and the
+	// sudo prompt both need a terminal, and only the tools that will run
+	// again are checked.
+	if !term.IsTerminal(int(os.Stdin.Fd())) {
+		fmt.Fprintln(os.Stderr, "Error: resume needs an interactive terminal (stdin is not a TTY).")
+		return 2
+	}
+	left := make(map[string]bool)
+	for _, name := range r.JobNames() {
+		left[name] = true
+	}
+	var pending []config.Template
+	for _, t := range runCfg.Tools {
+		if left[t.Name] {
+			pending = append(pending, t)
+		}
+	}
+	if err := wizard.Preflight(pending, runCfg.Commands); err != nil {
+		fmt.Fprintf(os.Stderr, "Error: %v\n", err)
+		return 1
+	}
+
+	runScan(runCfg, r)
+	return 0
 }
 
 func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.ReportData {
@@ -142,9 +409,25 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 
 	toolResults := make([]report.ToolResult, 0, len(cfg.Tools))
 	for _, t := range cfg.Tools {
-		res := resultMap[t.Name]
+		res, ok := resultMap[t.Name]
 		safeName := config.SanitizeName(t.Name)
 
+		// An interrupted scan leaves tools that never started
+		if !ok {
+			toolResults = append(toolResults, report.ToolResult{
+				Name:        t.Name,
+				Category:    strings.ToUpper(t.Category),
+				Description: t.Description,
+				Command:     cfg.Commands[t.Name],
+				Status:      "Not run",
+				Duration:    fmtDuration(0),
+				Stderr:      "not run: scan was interrupted",
+				Error:       "not run: scan was interrupted",
+				Failed:      true,
+			})
+			continue
+		}
+
 		// Determine status string
 		status := "Success"
 		failed := false
@@ -155,14 +438,14 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 		case runner.StatusSkipped:
 			status = "Skipped"
 			failed = true
+		case runner.StatusTimedOut:
+			status = "Timed out"
+			failed = true
 		}
 
 		// Skipped tools have no output files — just record the skip reason
 		if res.Status == runner.StatusSkipped {
-			errMsg := ""
-			if res.Err != nil {
-				errMsg = res.Err.Error()
-			}
+			errMsg := errString(res.Err)
 			toolResults = append(toolResults, report.ToolResult{
 				Name:        t.Name,
 				Category:    strings.ToUpper(t.Category),
@@ -171,6 +454,7 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 				Status:      status,
 				Duration:    fmtDuration(res.Duration),
 				Stderr:      errMsg,
+				Error:       errMsg,
 				Failed:      failed,
 			})
 			continue
@@ -183,7 +467,13 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 
 		switch t.OutputFormat {
 		case "nmap_xml":
-			formatted, err := report.FormatNmapXML(filepath.Join(cfg.OutputDir, "raw", "nmap.xml"))
+			// Each nmap template names its own output, e.g. TCP and UDP
+			// passes write raw/nmap.xml and raw/nmap_udp.xml.
+			xmlPath := report.NmapXMLPath(cfg.Commands[t.Name])
+			if xmlPath == "" {
+				xmlPath = filepath.Join(cfg.OutputDir, "raw", "nmap.xml")
+			}
+			formatted, err := report.FormatNmapXML(xmlPath)
 			if err != nil {
 				// Fallback: read raw stdout capture
 				raw, _ := os.ReadFile(filepath.Join(cfg.OutputDir, "raw", safeName+".txt"))
@@ -197,6 +487,12 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 			output = strings.TrimRight(string(raw), "\n")
 		}
 
+		// Keep report.md readable when a tool floods stdout
+		var omitted int
+		if !isFormatted {
+			output, omitted = report.CapLines(output, report.MaxOutputLines)
+		}
+
 		toolResults = append(toolResults, report.ToolResult{
 			Name:        t.Name,
 			Category:    strings.ToUpper(t.Category),
@@ -206,8 +502,12 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 			Duration:    fmtDuration(res.Duration),
 			Output:      output,
 			Stderr:      strings.TrimRight(string(errData), "\n"),
+			Error:       errString(res.Err),
 			Failed:      failed,
 			IsFormatted: isFormatted,
+			Truncated:   res.Truncated,
+			Omitted:     omitted,
+			RawPath:     filepath.Join("raw", safeName+".txt"),
 		})
 	}
 
@@ -218,6 +518,14 @@ func buildReportData(cfg *wizard.RunConfig, results []runner.JobResult) report.R
 	}
 }
 
+// errString returns err's message, or "" for a nil error.
+func errString(err error) string {
+	if err == nil {
+		return ""
+	}
+	return err.Error()
+}
+
 func fmtDuration(d time.Duration) string {
 	if d < time.Second {
 		return fmt.Sprintf("%dms", d.Milliseconds())
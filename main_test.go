@@ -0,0 +1,214 @@
+package main
+
+import (
+	"context"
+	"encoding/json"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/neur0map/ipcrawler/internal/config"
+	"github.com/neur0map/ipcrawler/internal/fsutil"
+	"github.com/neur0map/ipcrawler/internal/report"
+	"github.com/neur0map/ipcrawler/internal/runner"
+	"github.com/neur0map/ipcrawler/internal/wizard"
+)
+
+// TestPipeline runs the engine end to end — runner, dependency chain,
+// report data assembly, markdown compilation, and results.json — using
+// shell builtins as stand-in tools so no external binaries are required.
+func TestPipeline(t *testing.T) {
+	if testing.Short() {
+		t.Skip("skipping pipeline test in short mode")
+	}
+
+	outDir := t.TempDir()
+	if err := fsutil.MakeScanDirs(outDir); err != nil {
+		t.Fatal(err)
+	}
+
+	cfg := &wizard.RunConfig{
+		Target: "127.0.0.1",
+		Tools: []config.Template{
+			{Name: "Echo Probe", Category: "network", Timeout: "5s", Priority: 10},
+			{Name: "Echo Follow", Category: "recon", Timeout: "5s", Priority: 20, DependsOn: []string{"Echo Probe"}},
+			{Name: "Broken Tool", Category: "web", Timeout: "5s", Priority: 20},
+			{Name: "Noisy Tool", Category: "web", Timeout: "5s", Priority: 20, MaxOutputMB: 1},
+		},
+		Workers:   2,
+		OutputDir: outDir,
+		Commands: map[string]string{
+			"Echo Probe":  "echo 'port 80 open'",
+			"Echo Follow": "echo 'followed probe'",
+			"Broken Tool": "echo 'bad flag' >&2; exit 3",
+			"Noisy Tool":  "yes | head -n 600000",
+		},
+	}
+
+	r := runner.New(cfg)
+	done := make(chan struct{})
+	go func() {
+		r.Execute(context.Background())
+		close(done)
+	}()
+	for range r.Updates {
+	}
+	<-done
+
+	data := buildReportData(cfg, r.Results())
+	if len(data.Results) != 4 {
+		t.Fatalf("expected 4 report results, got %d", len(data.Results))
+	}
+
+	byName := make(map[string]report.ToolResult, len(data.Results))
+	for _, res := range data.Results {
+		byName[res.Name] = res
+	}
+	if got := byName["Echo Probe"]; got.Status != "Success" || got.Output != "port 80 open" {
+		t.Errorf("Echo Probe = %+v", got)
+	}
+	if got := byName["Echo Follow"]; got.Status != "Success" || got.Output != "followed probe" {
+		t.Errorf("Echo Follow = %+v", got)
+	}
+	if got := byName["Broken Tool"]; got.Status != "Failed" || got.Stderr != "bad flag" {
+		t.Errorf("Broken Tool = %+v", got)
+	}
+
+	if err := report.Compile(outDir, data); err != nil {
+		t.Fatalf("Compile: %v", err)
+	}
+	md, err := os.ReadFile(filepath.Join(outDir, "report.md"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	for _, want := range []string{"## [NETWORK] Echo Probe", "port 80 open", "## [WEB] Broken Tool", "bad flag"} {
+		if !strings.Contains(string(md), want) {
+			t.Errorf("report.md missing %q", want)
+		}
+	}
+
+	if err := report.WriteJSON(outDir, data); err != nil {
+		t.Fatalf("WriteJSON: %v", err)
+	}
+	raw, err := os.ReadFile(filepath.Join(outDir, "results.json"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	var results struct {
+		Results []struct {
+			Name      string `json:"name"`
+			Command   string `json:"command"`
+			Status    string `json:"status"`
+			Truncated bool   `json:"truncated"`
+		} `json:"results"`
+	}
+	if err := json.Unmarshal(raw, &results); err != nil {
+		t.Fatalf("results.json is not valid JSON: %v", err)
+	}
+	if len(results.Results) != 4 {
+		t.Fatalf("results.json has %d results, want 4", len(results.Results))
+	}
+	for _, res := range results.Results {
+		if res.Command != cfg.Commands[res.Name] {
+			t.Errorf("%s command = %q, want %q", res.Name, res.Command, cfg.Commands[res.Name])
+		}
+		wantStatus, wantTruncated := "Success", res.Name == "Noisy Tool"
+		if res.Name == "Broken Tool" {
+			wantStatus = "Failed"
+		}
+		if res.Status != wantStatus || res.Truncated != wantTruncated {
+			t.Errorf("%s status = %q truncated = %v, want %q and %v",
+				res.Name, res.Status, res.Truncated, wantStatus, wantTruncated)
+		}
+	}
+}
+
+func TestSortToolsStableOrder(t *testing.T) {
+	want := []string{"Ping", "Amass Intel rDNS", "Subdomain Resolver", "Whois", "Curl Headers", "Nmap SV Scan"}
+
+	// Same set, two different input orders — output must match.
+	inputs := [][]config.Template{
+		{
+			{Name: "Nmap SV Scan", Category: "network", Priority: 50},
+			{Name: "Curl Headers", Category: "web", Priority: 20},
+			{Name: "Subdomain Resolver", Category: "recon", Priority: 15},
+			{Name: "Whois", Category: "dns", Priority: 20},
+			{Name: "Amass Intel rDNS", Category: "recon", Priority: 15},
+			{Name: "Ping", Category: "network", Priority: 10},
+		},
+		{
+			{Name: "Ping", Category: "network", Priority: 10},
+			{Name: "Whois", Category: "dns", Priority: 20},
+			{Name: "Amass Intel rDNS", Category: "recon", Priority: 15},
+			{Name: "Curl Headers", Category: "web", Priority: 20},
+			{Name: "Nmap SV Scan", Category: "network", Priority: 50},
+			{Name: "Subdomain Resolver", Category: "recon", Priority: 15},
+		},
+	}
+
+	for i, tools := range inputs {
+		sortTools(tools)
+		for j, tool := range tools {
+			if tool.Name != want[j] {
+				t.Errorf("input %d: position %d = %s, want %s", i, j, tool.Name, want[j])
+			}
+		}
+	}
+}
+
+// TestSubdomainResolverWildcardFilter runs the embedded Subdomain Resolver
+// command against stub subfinder/dnsx binaries. dnsx only emits JSON in
+// -wd mode, so the hostnames must be pulled out before the resolve stage.
+func TestSubdomainResolverWildcardFilter(t *testing.T) {
+	templates, _, err := config.LoadTemplates(templateFS, nil)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var command string
+	for _, tmpl := range templates {
+		if tmpl.Name == "Subdomain Resolver" {
+			command = tmpl.ResolveCommand("example.com")
+		}
+	}
+	if command == "" {
+		t.Fatal("Subdomain Resolver template not found")
+	}
+
+	bin, raw := t.TempDir(), t.TempDir()
+	stubs := map[string]string{
+		"dnsx": `#!/bin/sh
+case "$*" in
+*-wd*) printf '%s\n' '{"host":"a.example.com","a":["10.0.0.1"]}' '{"host":"b.example.com","a":["10.0.0.2"]}' ;;
+*)
+	while [ $# -gt 0 ]; do [ "$1" = -l ] && list=$2; shift; done
+	while read -r h; do echo "$h [10.0.0.9]"; done < "$list" ;;
+esac
+`,
+		"subfinder": "#!/bin/sh\nprintf '%s\\n' a.example.com b.example.com wild.example.com\n",
+	}
+	for name, body := range stubs {
+		if err := os.WriteFile(filepath.Join(bin, name), []byte(body), 0755); err != nil {
+			t.Fatal(err)
+		}
+	}
+
+	cmd := exec.Command("sh", "-c", strings.ReplaceAll(command, "{raw_dir}", raw))
+	cmd.Env = append(os.Environ(), "PATH="+bin+string(os.PathListSeparator)+os.Getenv("PATH"))
+	out, err := cmd.CombinedOutput()
+	if err != nil {
+		t.Fatalf("command failed: %v\n%s", err, out)
+	}
+
+	if !strings.Contains(string(out), "wildcard filter: dropped 1 of 3 subdomains") {
+		t.Errorf("output missing filtered count:\n%s", out)
+	}
+	resolved, err := os.ReadFile(filepath.Join(raw, "dns_resolved.txt"))
+	if err != nil {
+		t.Fatal(err)
+	}
+	if want := "10.0.0.9 a.example.com\n10.0.0.9 b.example.com\n"; string(resolved) != want {
+		t.Errorf("dns_resolved.txt = %q, want %q", resolved, want)
+	}
+}